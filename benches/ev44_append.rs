@@ -0,0 +1,66 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use filewriter::ev44::Ev44;
+use filewriter::ev44_events_generated::root_as_event_44_message_with_opts;
+use filewriter::fixtures::Ev44Builder;
+use filewriter::writers::ev44::Ev44Writer;
+use filewriter::writers::{FlushPolicy, Writer};
+
+const PULSES_PER_MESSAGE: usize = 14;
+
+fn create_flatbuffer(num_events: usize) -> Vec<u8> {
+    let tofs: Vec<i32> = (0..num_events as i32).map(|i| i % 71_428_571).collect();
+    let pixels: Vec<i32> = (0..num_events as i32).map(|i| i % 100_000).collect();
+
+    let mut builder = Ev44Builder::new("SomeSource").message_id(123);
+    for pulse in 0..PULSES_PER_MESSAGE {
+        let start = pulse * num_events / PULSES_PER_MESSAGE;
+        let end = (pulse + 1) * num_events / PULSES_PER_MESSAGE;
+        let time = 1_700_000_000_000_000_000 + pulse as i64 * 71_428_571;
+        builder = builder.pulse(time, &tofs[start..end], &pixels[start..end]);
+    }
+    builder.build()
+}
+
+fn bench_ev44_append(c: &mut Criterion) {
+    let path = std::env::temp_dir().join("filewriter_ev44_append_bench.nxs");
+    let hfile = hdf5::File::create(&path).unwrap();
+    // Multi-million event messages are bigger than the verifier's default limits.
+    let opts = flatbuffers::VerifierOptions {
+        max_tables: usize::MAX,
+        max_apparent_size: usize::MAX,
+        ..Default::default()
+    };
+
+    let options = Ev44Writer::default_options();
+
+    let mut group = c.benchmark_group("ev44_append");
+    group.sample_size(10);
+    for num_events in [1_000_000, 4_000_000, 8_000_000] {
+        let buf = create_flatbuffer(num_events);
+        // Copying into a buffer offset by one byte forces the misaligned fallback.
+        let mut shifted = vec![0_u8; buf.len() + 1];
+        shifted[1..].copy_from_slice(&buf);
+
+        group.throughput(Throughput::Elements(num_events as u64));
+        for (name, data) in [("aligned", &buf[..]), ("misaligned", &shifted[1..])] {
+            let nx_group = hfile.create_group(&format!("{name}_{num_events}")).unwrap();
+            let mut writer =
+                Ev44Writer::create(&nx_group, &options, FlushPolicy::default()).unwrap();
+            group.bench_with_input(BenchmarkId::new(name, num_events), data, |b, data| {
+                b.iter(|| {
+                    let message = root_as_event_44_message_with_opts(&opts, data).unwrap();
+                    let message = Ev44::from_message(message).unwrap();
+                    writer.write_message(&message).unwrap();
+                    writer.flush().unwrap();
+                })
+            });
+        }
+    }
+    group.finish();
+
+    drop(hfile);
+    let _ = std::fs::remove_file(path);
+}
+
+criterion_group!(benches, bench_ev44_append);
+criterion_main!(benches);
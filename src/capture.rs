@@ -0,0 +1,403 @@
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+pub const MAGIC: &[u8; 8] = b"FWCAPTUR";
+/// The version written; older versions can still be read.
+pub const VERSION: u16 = 2;
+
+/// Used for the partition and offset of records from captures which didn't store them.
+pub const UNKNOWN: i64 = -1;
+
+#[derive(Debug)]
+pub enum CaptureError {
+    Io(std::io::Error),
+    NotACapture,
+    UnsupportedVersion(u16),
+    /// The file ends part way through a record.
+    Truncated,
+    InvalidRecord(&'static str),
+}
+
+impl std::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureError::Io(err) => write!(f, "{err}"),
+            CaptureError::NotACapture => write!(f, "not a capture file"),
+            CaptureError::UnsupportedVersion(version) => {
+                write!(f, "unsupported capture version {version}")
+            }
+            CaptureError::Truncated => write!(f, "capture file is truncated"),
+            CaptureError::InvalidRecord(reason) => write!(f, "invalid record: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+impl From<std::io::Error> for CaptureError {
+    fn from(err: std::io::Error) -> Self {
+        if err.kind() == ErrorKind::UnexpectedEof {
+            CaptureError::Truncated
+        } else {
+            CaptureError::Io(err)
+        }
+    }
+}
+
+/// A recorded message, so files can be written without Kafka.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaptureRecord {
+    pub timestamp: SystemTime,
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub schema: String,
+    pub source: String,
+    pub data: Vec<u8>,
+}
+
+/// Writes records to a capture, e.g. a file.
+///
+/// The capture starts with [`MAGIC`], a `u16` version and the table of topics:
+/// a `u16` count followed by each name. Each record is then:
+/// - `u64` timestamp in ns since the epoch
+/// - `u16` index into the topics
+/// - `i32` partition and `i64` offset
+/// - the 4 byte schema id
+/// - `u16` length and the source name
+/// - `u32` length and the flatbuffer
+///
+/// Version 1 had no topic table or partition and offset, and each record held the
+/// topic name instead of an index. Everything is little-endian.
+pub struct CaptureWriter<W: Write> {
+    inner: W,
+    topics: Vec<String>,
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Starts a capture of the given topics.
+    pub fn new(mut inner: W, topics: &[String]) -> Result<CaptureWriter<W>, CaptureError> {
+        inner.write_all(MAGIC)?;
+        inner.write_all(&VERSION.to_le_bytes())?;
+        let count = u16::try_from(topics.len())
+            .map_err(|_| CaptureError::InvalidRecord("too many topics"))?;
+        inner.write_all(&count.to_le_bytes())?;
+        for topic in topics {
+            write_string(&mut inner, topic)?;
+        }
+        Ok(CaptureWriter {
+            inner,
+            topics: topics.to_vec(),
+        })
+    }
+
+    pub fn write(&mut self, record: &CaptureRecord) -> Result<(), CaptureError> {
+        let timestamp = record
+            .timestamp
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        let topic = self
+            .topics
+            .iter()
+            .position(|t| *t == record.topic)
+            .ok_or(CaptureError::InvalidRecord("topic is not in the capture"))?
+            as u16;
+        let schema: &[u8; 4] = record
+            .schema
+            .as_bytes()
+            .try_into()
+            .map_err(|_| CaptureError::InvalidRecord("schema id must be 4 bytes"))?;
+        self.inner.write_all(&timestamp.to_le_bytes())?;
+        self.inner.write_all(&topic.to_le_bytes())?;
+        self.inner.write_all(&record.partition.to_le_bytes())?;
+        self.inner.write_all(&record.offset.to_le_bytes())?;
+        self.inner.write_all(schema)?;
+        write_string(&mut self.inner, &record.source)?;
+        let len = u32::try_from(record.data.len())
+            .map_err(|_| CaptureError::InvalidRecord("message too large"))?;
+        self.inner.write_all(&len.to_le_bytes())?;
+        self.inner.write_all(&record.data)?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+fn write_string<W: Write>(writer: &mut W, value: &str) -> Result<(), CaptureError> {
+    let len =
+        u16::try_from(value.len()).map_err(|_| CaptureError::InvalidRecord("name too long"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(value.as_bytes())?;
+    Ok(())
+}
+
+/// Reads the records from a capture in the order they were written.
+///
+/// Stops after the first error, as the rest of the file can't be trusted.
+pub struct CaptureReader<R: Read> {
+    inner: R,
+    version: u16,
+    /// Empty for version 1, where each record names its topic.
+    topics: Vec<String>,
+    failed: bool,
+}
+
+impl CaptureReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, CaptureError> {
+        CaptureReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> CaptureReader<R> {
+    pub fn new(mut inner: R) -> Result<CaptureReader<R>, CaptureError> {
+        let mut magic = [0; 8];
+        inner
+            .read_exact(&mut magic)
+            .map_err(|_| CaptureError::NotACapture)?;
+        if &magic != MAGIC {
+            return Err(CaptureError::NotACapture);
+        }
+        let version = u16::from_le_bytes(read_array(&mut inner)?);
+        let mut topics = Vec::new();
+        match version {
+            1 => {}
+            2 => {
+                let count = u16::from_le_bytes(read_array(&mut inner)?);
+                for _ in 0..count {
+                    topics.push(read_string(&mut inner)?);
+                }
+            }
+            _ => return Err(CaptureError::UnsupportedVersion(version)),
+        }
+        Ok(CaptureReader {
+            inner,
+            version,
+            topics,
+            failed: false,
+        })
+    }
+
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// The topics in the capture; only known up front from version 2.
+    pub fn topics(&self) -> &[String] {
+        &self.topics
+    }
+
+    fn read_record(&mut self) -> Result<Option<CaptureRecord>, CaptureError> {
+        let mut timestamp = [0; 8];
+        // Only the end of the file between records is a clean end.
+        let mut read = 0;
+        while read < timestamp.len() {
+            match self.inner.read(&mut timestamp[read..])? {
+                0 if read == 0 => return Ok(None),
+                0 => return Err(CaptureError::Truncated),
+                n => read += n,
+            }
+        }
+        let timestamp =
+            SystemTime::UNIX_EPOCH + Duration::from_nanos(u64::from_le_bytes(timestamp));
+        let (topic, partition, offset) = if self.version == 1 {
+            (read_string(&mut self.inner)?, UNKNOWN as i32, UNKNOWN)
+        } else {
+            let index = u16::from_le_bytes(read_array(&mut self.inner)?) as usize;
+            let topic = self
+                .topics
+                .get(index)
+                .ok_or(CaptureError::InvalidRecord("unknown topic index"))?
+                .clone();
+            let partition = i32::from_le_bytes(read_array(&mut self.inner)?);
+            let offset = i64::from_le_bytes(read_array(&mut self.inner)?);
+            (topic, partition, offset)
+        };
+        let schema = String::from_utf8(read_array::<_, 4>(&mut self.inner)?.to_vec())
+            .map_err(|_| CaptureError::InvalidRecord("schema id is not UTF-8"))?;
+        let source = read_string(&mut self.inner)?;
+        let len = u32::from_le_bytes(read_array(&mut self.inner)?) as usize;
+        let mut data = vec![0; len];
+        self.inner.read_exact(&mut data)?;
+        Ok(Some(CaptureRecord {
+            timestamp,
+            topic,
+            partition,
+            offset,
+            schema,
+            source,
+            data,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = Result<CaptureRecord, CaptureError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self.read_record();
+        self.failed = result.is_err();
+        result.transpose()
+    }
+}
+
+fn read_array<R: Read, const N: usize>(reader: &mut R) -> Result<[u8; N], CaptureError> {
+    let mut buf = [0; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, CaptureError> {
+    let len = u16::from_le_bytes(read_array(reader)?) as usize;
+    let mut buf = vec![0; len];
+    reader.read_exact(&mut buf)?;
+    String::from_utf8(buf).map_err(|_| CaptureError::InvalidRecord("name is not UTF-8"))
+}
+
+/// The times of the first and last records, or None if the capture is empty.
+pub fn time_range<P: AsRef<Path>>(
+    path: P,
+) -> Result<Option<(SystemTime, SystemTime)>, CaptureError> {
+    let mut range: Option<(SystemTime, SystemTime)> = None;
+    for record in CaptureReader::open(path)? {
+        let timestamp = record?.timestamp;
+        range = Some(match range {
+            None => (timestamp, timestamp),
+            Some((first, last)) => (first.min(timestamp), last.max(timestamp)),
+        });
+    }
+    Ok(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(topic: &str, secs: u64) -> CaptureRecord {
+        CaptureRecord {
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+            topic: topic.to_owned(),
+            partition: 1,
+            offset: secs as i64 * 10,
+            schema: "f144".to_owned(),
+            source: "temperature".to_owned(),
+            data: vec![1, 2, 3, 4],
+        }
+    }
+
+    fn topics() -> Vec<String> {
+        vec!["motion".to_owned(), "detector".to_owned()]
+    }
+
+    fn capture(records: &[CaptureRecord]) -> Vec<u8> {
+        let mut writer = CaptureWriter::new(Vec::new(), &topics()).unwrap();
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        writer.into_inner()
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let records = [record("motion", 1), record("detector", 2)];
+        let buf = capture(&records);
+
+        let reader = CaptureReader::new(buf.as_slice()).unwrap();
+        assert_eq!(reader.topics(), topics());
+        let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(read, records);
+    }
+
+    #[test]
+    fn empty_capture_has_no_records() {
+        let buf = capture(&[]);
+
+        assert_eq!(CaptureReader::new(buf.as_slice()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn version_1_can_still_be_read() {
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&1u16.to_le_bytes());
+        buf.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+        buf.extend_from_slice(&6u16.to_le_bytes());
+        buf.extend_from_slice(b"motion");
+        buf.extend_from_slice(b"f144");
+        buf.extend_from_slice(&11u16.to_le_bytes());
+        buf.extend_from_slice(b"temperature");
+        buf.extend_from_slice(&4u32.to_le_bytes());
+        buf.extend_from_slice(&[1, 2, 3, 4]);
+
+        let read: Vec<_> = CaptureReader::new(buf.as_slice())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let mut expected = record("motion", 1);
+        expected.partition = UNKNOWN as i32;
+        expected.offset = UNKNOWN;
+        assert_eq!(read, [expected]);
+    }
+
+    #[test]
+    fn other_files_are_rejected() {
+        let result = CaptureReader::new(&b"{\"children\": []}"[..]);
+
+        assert!(matches!(result, Err(CaptureError::NotACapture)));
+    }
+
+    #[test]
+    fn newer_versions_are_rejected() {
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&99u16.to_le_bytes());
+
+        let result = CaptureReader::new(buf.as_slice());
+
+        assert!(matches!(result, Err(CaptureError::UnsupportedVersion(99))));
+    }
+
+    #[test]
+    fn truncated_record_is_an_error_and_ends_the_reading() {
+        let mut buf = capture(&[record("motion", 1), record("motion", 2)]);
+        buf.truncate(buf.len() - 2);
+
+        let mut reader = CaptureReader::new(buf.as_slice()).unwrap();
+
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(reader.next(), Some(Err(CaptureError::Truncated))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn schema_must_be_four_bytes() {
+        let mut writer = CaptureWriter::new(Vec::new(), &topics()).unwrap();
+        let mut bad = record("motion", 1);
+        bad.schema = "f14".to_owned();
+
+        assert!(matches!(
+            writer.write(&bad),
+            Err(CaptureError::InvalidRecord(_))
+        ));
+    }
+
+    #[test]
+    fn topic_must_be_in_the_capture() {
+        let mut writer = CaptureWriter::new(Vec::new(), &topics()).unwrap();
+
+        assert!(matches!(
+            writer.write(&record("other", 1)),
+            Err(CaptureError::InvalidRecord(_))
+        ));
+    }
+}
@@ -0,0 +1,624 @@
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use serde::Deserialize;
+
+use crate::status::host_name;
+use crate::template::parse_variable;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Writes NeXus files from data streamed via Kafka")]
+pub struct Cli {
+    /// How much to log: off, error, warn, info, debug or trace.
+    #[arg(long, global = true)]
+    pub log_level: Option<LevelFilter>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Runs as a service, writing files when asked to via the command topic.
+    Serve(ServiceArgs),
+    /// Writes a single file from a template and then exits.
+    Write(WriteArgs),
+    /// Writes a file from recorded messages instead of Kafka.
+    Replay(ReplayArgs),
+    /// Records the messages on the topics used by a template for replaying later.
+    Record(RecordArgs),
+    /// Checks a template for problems without writing anything.
+    Validate(ValidateArgs),
+    /// Shows the tree of groups and datasets a template would produce.
+    DryRun(DryRunArgs),
+    /// Makes a template from an existing file.
+    Export(ExportArgs),
+    /// Makes a file left by an interrupted job consistent, using its journal.
+    Recover(RecoverArgs),
+}
+
+/// The service settings, which can also be given in a JSON config file.
+///
+/// Anything given on the command line takes precedence over the config file.
+#[derive(Debug, Default, PartialEq, Args, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServiceArgs {
+    /// JSON file containing any of the settings below, using underscores in the
+    /// names, e.g. "command_topic".
+    #[arg(long)]
+    #[serde(skip)]
+    pub config: Option<PathBuf>,
+
+    /// Kafka broker address [default: localhost:9092]
+    #[arg(long)]
+    pub broker: Option<String>,
+
+    /// Topic the run start and stop commands are sent to [default: filewriter_commands]
+    #[arg(long)]
+    pub command_topic: Option<String>,
+
+    /// Topic the status messages are sent to [default: filewriter_status]
+    #[arg(long)]
+    pub status_topic: Option<String>,
+
+    /// Identifies this instance in commands and status messages [default:
+    /// filewriter-<host>-<pid>]
+    #[arg(long)]
+    pub service_id: Option<String>,
+
+    /// Directory the files are written to [default: .]
+    #[arg(long)]
+    pub output_dir: Option<PathBuf>,
+
+    /// Where each file goes within the output directory, with placeholders such as
+    /// `$PROPOSAL$/$DATE$/$JOB_ID$.nxs` [default: $FILENAME$]
+    #[arg(long)]
+    pub filename_template: Option<String>,
+
+    /// Replace files which already exist instead of refusing the job.
+    #[arg(long, num_args = 0, default_missing_value = "true")]
+    pub overwrite: Option<bool>,
+
+    /// Record how many messages each source received, wrote and dropped as
+    /// attributes on its module's group.
+    #[arg(long, num_args = 0, default_missing_value = "true")]
+    pub stats_attributes: Option<bool>,
+
+    /// Don't let the files be read while they are being written (SWMR), e.g. on NFS
+    /// which doesn't respect POSIX write ordering.
+    #[arg(long, num_args = 0, default_missing_value = "true")]
+    pub no_swmr: Option<bool>,
+
+    /// Refuse jobs when the output directory has less than this many MB free
+    /// [default: 1024]
+    #[arg(long)]
+    pub min_free_mb: Option<u64>,
+
+    /// Directory the templates' includes are found in [default: .]
+    #[arg(long)]
+    pub template_dir: Option<PathBuf>,
+
+    /// Seconds between status messages [default: 2]
+    #[arg(long)]
+    pub status_interval: Option<u64>,
+
+    /// How many jobs can be written at the same time; starts beyond this are
+    /// refused [default: 1]
+    #[arg(long)]
+    pub max_jobs: Option<NonZeroUsize>,
+
+    /// What to do with partial files left in the output directory by a previous
+    /// run [default: keep]
+    #[arg(long, value_enum)]
+    pub partial_files: Option<PartialFiles>,
+
+    /// Only settable via the config file; the command line uses the global option.
+    #[arg(skip)]
+    pub log_level: Option<LevelFilter>,
+}
+
+/// What to do at startup with files which a previous run didn't finish writing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PartialFiles {
+    /// Leave them alone; another instance might still be writing them.
+    Keep,
+    Delete,
+    /// Trim them using their journals and give them their final names.
+    Recover,
+}
+
+/// Settings for writing a single file without running the service.
+#[derive(Debug, PartialEq, Args)]
+pub struct WriteArgs {
+    /// The nexus_structure JSON describing the file.
+    #[arg(long)]
+    pub template: PathBuf,
+
+    /// The file to write.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// Replace the output file if it already exists.
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// Record how many messages each source received, wrote and dropped as
+    /// attributes on its module's group.
+    #[arg(long)]
+    pub stats_attributes: bool,
+
+    /// Don't let the files be read while they are being written (SWMR), e.g. on NFS
+    /// which doesn't respect POSIX write ordering.
+    #[arg(long)]
+    pub no_swmr: bool,
+
+    /// Kafka broker the data is read from.
+    #[arg(long, default_value = "localhost:9092")]
+    pub broker: String,
+
+    /// Time of the first data to write in ms since the epoch [default: now]
+    #[arg(long)]
+    pub start: Option<u64>,
+
+    /// Time of the last data to write in ms since the epoch; if not given the
+    /// writing continues until the process is stopped.
+    #[arg(long)]
+    pub stop: Option<u64>,
+
+    /// Fills in a `$NAME$` placeholder in the template; may be repeated.
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = parse_variable)]
+    pub variables: Vec<(String, String)>,
+}
+
+/// Settings for writing a file from a capture.
+#[derive(Debug, PartialEq, Args)]
+pub struct ReplayArgs {
+    /// The capture file holding the recorded messages.
+    #[arg(long)]
+    pub capture: PathBuf,
+
+    /// The nexus_structure JSON describing the file.
+    #[arg(long)]
+    pub template: PathBuf,
+
+    /// The file to write.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// Replace the output file if it already exists.
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// Record how many messages each source received, wrote and dropped as
+    /// attributes on its module's group.
+    #[arg(long)]
+    pub stats_attributes: bool,
+
+    /// Don't let the files be read while they are being written (SWMR), e.g. on NFS
+    /// which doesn't respect POSIX write ordering.
+    #[arg(long)]
+    pub no_swmr: bool,
+
+    /// Time of the first data to write in ms since the epoch [default: the first
+    /// message]
+    #[arg(long)]
+    pub start: Option<u64>,
+
+    /// Time of the last data to write in ms since the epoch [default: the last
+    /// message]
+    #[arg(long)]
+    pub stop: Option<u64>,
+
+    /// Fills in a `$NAME$` placeholder in the template; may be repeated.
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = parse_variable)]
+    pub variables: Vec<(String, String)>,
+}
+
+/// Settings for recording messages into a capture.
+#[derive(Debug, PartialEq, Args)]
+pub struct RecordArgs {
+    /// The nexus_structure JSON; the topics of its streaming modules are recorded.
+    #[arg(long)]
+    pub template: PathBuf,
+
+    /// The capture file to write.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// Kafka broker the messages are read from.
+    #[arg(long, default_value = "localhost:9092")]
+    pub broker: String,
+
+    /// Time of the first message to record in ms since the epoch.
+    #[arg(long)]
+    pub start: u64,
+
+    /// Time of the last message to record in ms since the epoch.
+    #[arg(long)]
+    pub stop: u64,
+}
+
+/// Settings for checking a template.
+#[derive(Debug, PartialEq, Args)]
+pub struct ValidateArgs {
+    /// The nexus_structure JSON to check.
+    pub template: PathBuf,
+}
+
+/// Settings for showing what a template would produce.
+#[derive(Debug, PartialEq, Args)]
+pub struct DryRunArgs {
+    /// The nexus_structure JSON.
+    pub template: PathBuf,
+
+    /// Print the tree as JSON instead of text.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Fills in a `$NAME$` placeholder in the template; may be repeated.
+    #[arg(long = "var", value_name = "NAME=VALUE", value_parser = parse_variable)]
+    pub variables: Vec<(String, String)>,
+}
+
+/// Settings for making a template from an existing file.
+#[derive(Debug, PartialEq, Args)]
+pub struct ExportArgs {
+    /// The NeXus file to export.
+    pub file: PathBuf,
+
+    /// The template to write; large datasets are written to `.npy` files next to it.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    /// Datasets with more values than this are written to `.npy` files.
+    #[arg(long, default_value_t = 100)]
+    pub max_inline_values: usize,
+}
+
+/// Settings for recovering an interrupted file.
+#[derive(Debug, PartialEq, Args)]
+pub struct RecoverArgs {
+    /// The NeXus file; its journal is the same path with `.journal` added.
+    pub file: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, std::io::Error),
+    Invalid(PathBuf, serde_json::Error),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            ConfigError::Invalid(path, err) => {
+                write!(f, "invalid config {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The settings the service runs with, once the defaults have been filled in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceSettings {
+    pub broker: String,
+    pub command_topic: String,
+    pub status_topic: String,
+    pub service_id: String,
+    pub output_dir: PathBuf,
+    pub filename_template: String,
+    pub overwrite: bool,
+    pub min_free_bytes: u64,
+    pub stats_attributes: bool,
+    pub swmr: bool,
+    pub template_dir: PathBuf,
+    pub status_interval: Duration,
+    pub max_jobs: usize,
+    pub partial_files: PartialFiles,
+}
+
+impl ServiceArgs {
+    /// Fills in anything not given on the command line from the config file, if
+    /// there is one.
+    pub fn with_config_file(self) -> Result<ServiceArgs, ConfigError> {
+        let Some(path) = &self.config else {
+            return Ok(self);
+        };
+        let file = read_config(path)?;
+        Ok(self.or(file))
+    }
+
+    fn or(self, other: ServiceArgs) -> ServiceArgs {
+        ServiceArgs {
+            config: self.config.or(other.config),
+            broker: self.broker.or(other.broker),
+            command_topic: self.command_topic.or(other.command_topic),
+            status_topic: self.status_topic.or(other.status_topic),
+            service_id: self.service_id.or(other.service_id),
+            output_dir: self.output_dir.or(other.output_dir),
+            filename_template: self.filename_template.or(other.filename_template),
+            overwrite: self.overwrite.or(other.overwrite),
+            stats_attributes: self.stats_attributes.or(other.stats_attributes),
+            no_swmr: self.no_swmr.or(other.no_swmr),
+            min_free_mb: self.min_free_mb.or(other.min_free_mb),
+            template_dir: self.template_dir.or(other.template_dir),
+            status_interval: self.status_interval.or(other.status_interval),
+            max_jobs: self.max_jobs.or(other.max_jobs),
+            partial_files: self.partial_files.or(other.partial_files),
+            log_level: self.log_level.or(other.log_level),
+        }
+    }
+
+    /// Uses the defaults for anything not given.
+    pub fn resolve(self) -> ServiceSettings {
+        ServiceSettings {
+            broker: self.broker.unwrap_or_else(|| "localhost:9092".to_owned()),
+            command_topic: self
+                .command_topic
+                .unwrap_or_else(|| "filewriter_commands".to_owned()),
+            status_topic: self
+                .status_topic
+                .unwrap_or_else(|| "filewriter_status".to_owned()),
+            service_id: self
+                .service_id
+                .unwrap_or_else(|| format!("filewriter-{}-{}", host_name(), std::process::id())),
+            output_dir: self.output_dir.unwrap_or_else(|| PathBuf::from(".")),
+            filename_template: self
+                .filename_template
+                .unwrap_or_else(|| "$FILENAME$".to_owned()),
+            overwrite: self.overwrite.unwrap_or(false),
+            min_free_bytes: self.min_free_mb.unwrap_or(1024).saturating_mul(1024 * 1024),
+            stats_attributes: self.stats_attributes.unwrap_or(false),
+            swmr: !self.no_swmr.unwrap_or(false),
+            template_dir: self.template_dir.unwrap_or_else(|| PathBuf::from(".")),
+            status_interval: Duration::from_secs(self.status_interval.unwrap_or(2)),
+            max_jobs: self.max_jobs.map_or(1, NonZeroUsize::get),
+            partial_files: self.partial_files.unwrap_or(PartialFiles::Keep),
+        }
+    }
+}
+
+fn read_config(path: &Path) -> Result<ServiceArgs, ConfigError> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| ConfigError::Io(path.to_owned(), err))?;
+    serde_json::from_str(&contents).map_err(|err| ConfigError::Invalid(path.to_owned(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(std::iter::once("filewriter").chain(args.iter().copied())).unwrap()
+    }
+
+    fn service_args(cli: Cli) -> ServiceArgs {
+        match cli.command {
+            Command::Serve(args) => args,
+            command => panic!("expected serve, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn defaults_used_if_nothing_given() {
+        let settings = service_args(parse(&["serve"])).resolve();
+
+        assert_eq!(settings.broker, "localhost:9092");
+        assert_eq!(settings.command_topic, "filewriter_commands");
+        assert_eq!(settings.output_dir, PathBuf::from("."));
+        assert_eq!(settings.template_dir, PathBuf::from("."));
+        assert_eq!(settings.status_interval, Duration::from_secs(2));
+        assert_eq!(settings.max_jobs, 1);
+        assert_eq!(settings.partial_files, PartialFiles::Keep);
+        assert_eq!(settings.filename_template, "$FILENAME$");
+        assert!(!settings.overwrite);
+        assert_eq!(settings.min_free_bytes, 1024 * 1024 * 1024);
+        assert!(!settings.stats_attributes);
+        assert!(settings.swmr);
+    }
+
+    #[test]
+    fn parse_service_options() {
+        let cli = parse(&[
+            "serve",
+            "--broker",
+            "kafka:9092",
+            "--command-topic",
+            "commands",
+            "--service-id",
+            "writer_1",
+            "--status-interval",
+            "5",
+            "--log-level",
+            "debug",
+        ]);
+
+        assert_eq!(cli.log_level, Some(LevelFilter::Debug));
+        let settings = service_args(cli).resolve();
+        assert_eq!(settings.broker, "kafka:9092");
+        assert_eq!(settings.command_topic, "commands");
+        assert_eq!(settings.service_id, "writer_1");
+        assert_eq!(settings.status_interval, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn parse_output_options() {
+        let cli = parse(&[
+            "serve",
+            "--filename-template",
+            "$PROPOSAL$/$JOB_ID$.nxs",
+            "--overwrite",
+            "--min-free-mb",
+            "10",
+        ]);
+
+        let settings = service_args(cli).resolve();
+        assert_eq!(settings.filename_template, "$PROPOSAL$/$JOB_ID$.nxs");
+        assert!(settings.overwrite);
+        assert_eq!(settings.min_free_bytes, 10 * 1024 * 1024);
+    }
+
+    #[test]
+    fn max_jobs_must_be_at_least_one() {
+        let settings = service_args(parse(&["serve", "--max-jobs", "4"])).resolve();
+        assert_eq!(settings.max_jobs, 4);
+
+        let result = Cli::try_parse_from(["filewriter", "serve", "--max-jobs", "0"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn command_line_takes_precedence_over_config_file() {
+        let cli = ServiceArgs {
+            broker: Some("cli:9092".to_owned()),
+            ..Default::default()
+        };
+        let file: ServiceArgs = serde_json::from_str(
+            r#"{"broker": "file:9092", "status_topic": "status", "log_level": "warn"}"#,
+        )
+        .unwrap();
+
+        let args = cli.or(file);
+
+        assert_eq!(args.broker, Some("cli:9092".to_owned()));
+        assert_eq!(args.status_topic, Some("status".to_owned()));
+        assert_eq!(args.log_level, Some(LevelFilter::Warn));
+    }
+
+    #[test]
+    fn partial_file_policy_from_config_file() {
+        let file: ServiceArgs = serde_json::from_str(r#"{"partial_files": "recover"}"#).unwrap();
+
+        assert_eq!(file.resolve().partial_files, PartialFiles::Recover);
+    }
+
+    #[test]
+    fn unknown_config_setting_is_an_error() {
+        let result = serde_json::from_str::<ServiceArgs>(r#"{"brokers": "kafka:9092"}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_one_shot_write() {
+        let cli = parse(&[
+            "write",
+            "--template",
+            "nxs.json",
+            "--output",
+            "file.nxs",
+            "--start",
+            "1000",
+            "--stop",
+            "2000",
+        ]);
+
+        let Command::Write(args) = cli.command else {
+            panic!("expected write");
+        };
+        assert_eq!(args.template, PathBuf::from("nxs.json"));
+        assert_eq!(args.output, PathBuf::from("file.nxs"));
+        assert_eq!(args.broker, "localhost:9092");
+        assert_eq!(args.start, Some(1000));
+        assert_eq!(args.stop, Some(2000));
+    }
+
+    #[test]
+    fn template_variables_can_be_repeated() {
+        let cli = parse(&[
+            "write",
+            "--template",
+            "nxs.json",
+            "--output",
+            "file.nxs",
+            "--var",
+            "TITLE=Spin echo",
+            "--var",
+            "PROPOSAL=123",
+        ]);
+
+        let Command::Write(args) = cli.command else {
+            panic!("expected write");
+        };
+        assert_eq!(
+            args.variables,
+            vec![
+                ("TITLE".to_owned(), "Spin echo".to_owned()),
+                ("PROPOSAL".to_owned(), "123".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_replay() {
+        let cli = parse(&[
+            "replay",
+            "--capture",
+            "run.cap",
+            "--template",
+            "nxs.json",
+            "--output",
+            "file.nxs",
+        ]);
+
+        let Command::Replay(args) = cli.command else {
+            panic!("expected replay");
+        };
+        assert_eq!(args.capture, PathBuf::from("run.cap"));
+        assert_eq!(args.start, None);
+        assert_eq!(args.stop, None);
+    }
+
+    #[test]
+    fn record_needs_start_and_stop() {
+        let result = Cli::try_parse_from([
+            "filewriter",
+            "record",
+            "--template",
+            "nxs.json",
+            "--output",
+            "run.cap",
+            "--start",
+            "1000",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_dry_run() {
+        let cli = parse(&["dry-run", "nxs.json", "--json"]);
+
+        let Command::DryRun(args) = cli.command else {
+            panic!("expected dry run");
+        };
+        assert_eq!(args.template, PathBuf::from("nxs.json"));
+        assert!(args.json);
+    }
+
+    #[test]
+    fn parse_export() {
+        let cli = parse(&["export", "file.nxs", "--output", "nxs.json"]);
+
+        let Command::Export(args) = cli.command else {
+            panic!("expected export");
+        };
+        assert_eq!(args.file, PathBuf::from("file.nxs"));
+        assert_eq!(args.output, PathBuf::from("nxs.json"));
+        assert_eq!(args.max_inline_values, 100);
+    }
+
+    #[test]
+    fn one_shot_write_needs_template_and_output() {
+        let result = Cli::try_parse_from(["filewriter", "write", "--output", "file.nxs"]);
+
+        assert!(result.is_err());
+    }
+}
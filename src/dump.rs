@@ -0,0 +1,369 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use hdf5::types::{TypeDescriptor, VarLenAscii, VarLenUnicode};
+use hdf5::{Container, Group, Location};
+use serde::Serialize;
+
+use crate::json::{generate_structure, ModuleSettings};
+use crate::nexus::NexusStructure;
+use crate::output::create_in_memory;
+use crate::writers::{create_writer, FlushPolicy};
+
+/// Something in the file, as it would be after the job has started.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Node {
+    pub name: String,
+    #[serde(flatten)]
+    pub kind: NodeKind,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Attribute>,
+    /// The streaming module which writes into this group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<Stream>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Node>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NodeKind {
+    Group,
+    Dataset {
+        dtype: String,
+        shape: Vec<usize>,
+        /// None for dimensions which grow as data is written.
+        max_shape: Vec<Option<usize>>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Attribute {
+    pub name: String,
+    pub dtype: String,
+    /// Only string values are shown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Stream {
+    pub module: String,
+    pub topic: String,
+    pub source: String,
+}
+
+impl From<&ModuleSettings> for Stream {
+    fn from(module: &ModuleSettings) -> Self {
+        let key = module.source_key();
+        Stream {
+            module: key.schema,
+            topic: module.topic().to_owned(),
+            source: key.source,
+        }
+    }
+}
+
+/// Works out the tree a nexus_structure would produce, without writing a file.
+///
+/// The structure and the writers' datasets are created in memory using the same
+/// code as a real job, so what is shown is what would be written. The root
+/// `file_name` attribute is left out unless the template sets it, as there is no
+/// output file to name.
+pub fn dry_run(structure: &NexusStructure) -> hdf5::Result<Node> {
+    let mut file = create_in_memory()?;
+    let modules = generate_structure(structure, &mut file)?;
+    let writers = modules
+        .iter()
+        .map(|module| create_writer(&file, module, FlushPolicy::default()))
+        .collect::<hdf5::Result<Vec<_>>>()?;
+    let mut node = describe(&file, &modules)?;
+    drop(writers);
+    if structure.attributes.iter().all(|a| a.name != "file_name") {
+        node.attributes.retain(|a| a.name != "file_name");
+    }
+    Ok(node)
+}
+
+/// Describes everything in the file, noting where each streaming module writes.
+pub fn describe(file: &hdf5::File, modules: &[ModuleSettings]) -> hdf5::Result<Node> {
+    let streams = modules
+        .iter()
+        .map(|module| (module_path(module), Stream::from(module)))
+        .collect();
+    describe_group(file, "/", &streams)
+}
+
+fn module_path(module: &ModuleSettings) -> &str {
+    match module {
+        ModuleSettings::F144(settings) => &settings.path,
+        ModuleSettings::Ev44(settings) => &settings.path,
+    }
+}
+
+fn describe_group(
+    group: &Group,
+    name: &str,
+    streams: &HashMap<&str, Stream>,
+) -> hdf5::Result<Node> {
+    let mut children = Vec::new();
+    for child in group.groups()? {
+        let child_name = base_name(&child.name());
+        children.push(describe_group(&child, &child_name, streams)?);
+    }
+    for dataset in group.datasets()? {
+        let space = dataset.space()?;
+        children.push(Node {
+            name: base_name(&dataset.name()),
+            kind: NodeKind::Dataset {
+                dtype: dataset.dtype()?.to_descriptor()?.to_string(),
+                shape: space.shape(),
+                max_shape: space.maxdims(),
+            },
+            attributes: describe_attributes(&dataset)?,
+            stream: None,
+            children: Vec::new(),
+        });
+    }
+    children.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(Node {
+        name: name.to_owned(),
+        kind: NodeKind::Group,
+        attributes: describe_attributes(group)?,
+        stream: streams.get(group.name().as_str()).cloned(),
+        children,
+    })
+}
+
+pub(crate) fn describe_attributes(location: &Location) -> hdf5::Result<Vec<Attribute>> {
+    let mut attributes = Vec::new();
+    for name in location.attr_names()? {
+        let attr = location.attr(&name)?;
+        let dtype = attr.dtype()?.to_descriptor()?;
+        attributes.push(Attribute {
+            name,
+            dtype: dtype.to_string(),
+            value: read_string(&attr, &dtype),
+        });
+    }
+    Ok(attributes)
+}
+
+pub(crate) fn read_string(container: &Container, dtype: &TypeDescriptor) -> Option<String> {
+    if !container.is_scalar() {
+        return None;
+    }
+    match dtype {
+        TypeDescriptor::VarLenUnicode => container
+            .read_scalar::<VarLenUnicode>()
+            .ok()
+            .map(|v| v.to_string()),
+        TypeDescriptor::VarLenAscii => container
+            .read_scalar::<VarLenAscii>()
+            .ok()
+            .map(|v| v.to_string()),
+        _ => None,
+    }
+}
+
+pub(crate) fn base_name(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_owned()
+}
+
+const INDENT: &str = "   ";
+
+impl Node {
+    /// Writes the tree in a similar style to h5dump.
+    fn write_text(&self, out: &mut String, depth: usize) {
+        let indent = INDENT.repeat(depth);
+        match &self.kind {
+            NodeKind::Group => {
+                let _ = write!(out, "{indent}GROUP \"{}\"", self.name);
+            }
+            NodeKind::Dataset {
+                dtype,
+                shape,
+                max_shape,
+            } => {
+                let _ = write!(
+                    out,
+                    "{indent}DATASET \"{}\" {dtype} {}",
+                    self.name,
+                    format_shape(shape, max_shape)
+                );
+            }
+        }
+        if let Some(stream) = &self.stream {
+            let _ = write!(
+                out,
+                " <- {} \"{}\" on {}",
+                stream.module, stream.source, stream.topic
+            );
+        }
+        out.push('\n');
+        for attr in &self.attributes {
+            let _ = write!(
+                out,
+                "{indent}{INDENT}ATTRIBUTE \"{}\" {}",
+                attr.name, attr.dtype
+            );
+            if let Some(value) = &attr.value {
+                let _ = write!(out, " = {value:?}");
+            }
+            out.push('\n');
+        }
+        for child in &self.children {
+            child.write_text(out, depth + 1);
+        }
+    }
+}
+
+fn format_shape(shape: &[usize], max_shape: &[Option<usize>]) -> String {
+    if shape.is_empty() {
+        return "SCALAR".to_owned();
+    }
+    let join = |dims: Vec<String>| dims.join(", ");
+    let current = join(shape.iter().map(usize::to_string).collect());
+    if max_shape
+        .iter()
+        .zip(shape)
+        .all(|(max, dim)| *max == Some(*dim))
+    {
+        return format!("( {current} )");
+    }
+    let max = join(
+        max_shape
+            .iter()
+            .map(|max| max.map_or("UNLIMITED".to_owned(), |m| m.to_string()))
+            .collect(),
+    );
+    format!("( {current} ) / ( {max} )")
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        self.write_text(&mut out, 0);
+        f.write_str(&out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn dataset(name: &str, dtype: &str, shape: &[usize], max_shape: &[Option<usize>]) -> Node {
+        Node {
+            name: name.to_owned(),
+            kind: NodeKind::Dataset {
+                dtype: dtype.to_owned(),
+                shape: shape.to_vec(),
+                max_shape: max_shape.to_vec(),
+            },
+            attributes: Vec::new(),
+            stream: None,
+            children: Vec::new(),
+        }
+    }
+
+    fn tree() -> Node {
+        Node {
+            name: "/".to_owned(),
+            kind: NodeKind::Group,
+            attributes: Vec::new(),
+            stream: None,
+            children: vec![Node {
+                name: "log".to_owned(),
+                kind: NodeKind::Group,
+                attributes: vec![Attribute {
+                    name: "NX_class".to_owned(),
+                    dtype: "unicode (var len)".to_owned(),
+                    value: Some("NXlog".to_owned()),
+                }],
+                stream: Some(Stream {
+                    module: "f144".to_owned(),
+                    topic: "motion".to_owned(),
+                    source: "x".to_owned(),
+                }),
+                children: vec![
+                    dataset("matrix", "float64", &[2, 3], &[Some(2), Some(3)]),
+                    dataset("time", "int64", &[0], &[None]),
+                    dataset("title", "unicode (var len)", &[], &[]),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn text_dump_looks_like_h5dump() {
+        let expected = r#"GROUP "/"
+   GROUP "log" <- f144 "x" on motion
+      ATTRIBUTE "NX_class" unicode (var len) = "NXlog"
+      DATASET "matrix" float64 ( 2, 3 )
+      DATASET "time" int64 ( 0 ) / ( UNLIMITED )
+      DATASET "title" unicode (var len) SCALAR
+"#;
+
+        assert_eq!(tree().to_string(), expected);
+    }
+
+    #[test]
+    fn json_dump_includes_types_and_streams() {
+        let json = serde_json::to_value(tree()).unwrap();
+
+        let log = &json["children"][0];
+        assert_eq!(log["type"], "group");
+        assert_eq!(log["stream"]["topic"], "motion");
+        assert_eq!(log["attributes"][0]["value"], "NXlog");
+        let time = &log["children"][1];
+        assert_eq!(time["type"], "dataset");
+        assert_eq!(time["dtype"], "int64");
+        assert_eq!(time["max_shape"], serde_json::json!([null]));
+    }
+
+    #[test]
+    fn empty_groups_have_no_children_in_json() {
+        let json = serde_json::to_value(dataset("a", "int64", &[], &[])).unwrap();
+
+        assert!(json.get("children").is_none());
+    }
+
+    #[test]
+    fn dry_run_of_a_small_template() {
+        let structure = serde_json::from_value(json!({
+            "children": [{
+                "name": "entry",
+                "type": "group",
+                "attributes": {"NX_class": "NXentry"},
+                "children": [
+                    {"module": "dataset", "config": {"name": "title", "dtype": "string", "values": "Spin echo"}},
+                    {
+                        "name": "log",
+                        "type": "group",
+                        "children": [
+                            {"module": "f144", "config": {"source": "x", "topic": "motion", "dtype": "double"}},
+                        ],
+                    },
+                ],
+            }],
+        }))
+        .unwrap();
+
+        let root = dry_run(&structure).unwrap();
+
+        assert!(root.attributes.iter().all(|a| a.name != "file_name"));
+        assert!(root.attributes.iter().any(|a| a.name == "file_time"));
+        let entry = &root.children[0];
+        assert_eq!(entry.name, "entry");
+        assert_eq!(entry.attributes[0].value.as_deref(), Some("NXentry"));
+        let log = &entry.children[0];
+        assert_eq!(log.name, "log");
+        assert_eq!(log.stream.as_ref().unwrap().source, "x");
+        assert!(log.children.iter().any(|c| c.name == "value"));
+        let title = &entry.children[1];
+        assert_eq!(title.name, "title");
+        assert!(matches!(title.kind, NodeKind::Dataset { .. }));
+    }
+}
@@ -1,82 +1,402 @@
-extern crate flatbuffers;
 use flatbuffers::Vector;
 
-use crate::ev44_events_generated::root_as_event_44_message;
+use crate::ev44_events_generated::{
+    root_as_event_44_message, root_as_event_44_message_unchecked, Event44Message,
+};
 
-struct Ev44<'a> {
-    source: String,
-    message_id: i64,
-    reference_time: Vector<'a, i64>,
+/// Why an ev44 message can't be used.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ev44Error {
+    InvalidFlatbuffer(flatbuffers::InvalidFlatbuffer),
+    /// Every pulse needs both a time and an index into the events.
+    PulseLengthMismatch {
+        reference_time: usize,
+        reference_time_index: usize,
+    },
+    /// There must be a pixel id for every event, unless there are none at all
+    /// (e.g. for monitors).
+    EventLengthMismatch {
+        time_of_flight: usize,
+        pixel_id: usize,
+    },
+    /// The first events don't belong to any pulse.
+    EventsBeforeFirstPulse,
+    IndexNotMonotonic {
+        pulse: usize,
+    },
+    IndexOutOfRange {
+        pulse: usize,
+        index: i32,
+        events: usize,
+    },
 }
 
-impl Ev44<'_> {
-    fn get_source(buf: &[u8]) -> String {
-        let root = root_as_event_44_message(buf).unwrap();
-        root.source_name().to_string()
+impl std::fmt::Display for Ev44Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ev44Error::InvalidFlatbuffer(err) => write!(f, "invalid flatbuffer: {err}"),
+            Ev44Error::PulseLengthMismatch {
+                reference_time,
+                reference_time_index,
+            } => write!(
+                f,
+                "{reference_time} reference times but {reference_time_index} reference time indexes"
+            ),
+            Ev44Error::EventLengthMismatch {
+                time_of_flight,
+                pixel_id,
+            } => write!(
+                f,
+                "{time_of_flight} times of flight but {pixel_id} pixel ids"
+            ),
+            Ev44Error::EventsBeforeFirstPulse => {
+                write!(f, "first reference time index is not zero")
+            }
+            Ev44Error::IndexNotMonotonic { pulse } => {
+                write!(f, "reference time index decreases at pulse {pulse}")
+            }
+            Ev44Error::IndexOutOfRange {
+                pulse,
+                index,
+                events,
+            } => write!(
+                f,
+                "reference time index {index} at pulse {pulse} is outside the {events} events"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Ev44Error {}
+
+impl From<flatbuffers::InvalidFlatbuffer> for Ev44Error {
+    fn from(err: flatbuffers::InvalidFlatbuffer) -> Self {
+        Ev44Error::InvalidFlatbuffer(err)
+    }
+}
+
+/// A validated view of an ev44 message, borrowing from the flatbuffer.
+///
+/// Event `i` belongs to pulse `p` where `reference_time_index[p] <= i` and either
+/// `p` is the last pulse or `i < reference_time_index[p + 1]`.
+#[derive(Clone, Copy, Debug)]
+pub struct Ev44<'a> {
+    message: Event44Message<'a>,
+}
+
+impl<'a> Ev44<'a> {
+    pub fn from_bytes(buf: &'a [u8]) -> Result<Ev44<'a>, Ev44Error> {
+        Ev44::from_message(root_as_event_44_message(buf)?)
+    }
+
+    /// Checks a message which has already been parsed, e.g. with non-default
+    /// verifier options for very large messages.
+    pub fn from_message(message: Event44Message<'a>) -> Result<Ev44<'a>, Ev44Error> {
+        let ev44 = Ev44 { message };
+        ev44.validate()?;
+        Ok(ev44)
+    }
+
+    fn validate(&self) -> Result<(), Ev44Error> {
+        let reference_time = self.reference_time().len();
+        let reference_time_index = self.reference_time_index().len();
+        if reference_time != reference_time_index {
+            return Err(Ev44Error::PulseLengthMismatch {
+                reference_time,
+                reference_time_index,
+            });
+        }
+
+        let events = self.num_events();
+        let pixel_id = self.pixel_id().len();
+        if pixel_id != 0 && pixel_id != events {
+            return Err(Ev44Error::EventLengthMismatch {
+                time_of_flight: events,
+                pixel_id,
+            });
+        }
+
+        let mut previous = 0;
+        for (pulse, index) in self.reference_time_index().iter().enumerate() {
+            if pulse == 0 && index != 0 && events > 0 {
+                return Err(Ev44Error::EventsBeforeFirstPulse);
+            }
+            if index < previous {
+                return Err(Ev44Error::IndexNotMonotonic { pulse });
+            }
+            if index < 0 || index as usize > events {
+                return Err(Ev44Error::IndexOutOfRange {
+                    pulse,
+                    index,
+                    events,
+                });
+            }
+            previous = index;
+        }
+        if reference_time_index == 0 && events > 0 {
+            return Err(Ev44Error::EventsBeforeFirstPulse);
+        }
+        Ok(())
+    }
+
+    pub fn source_name(&self) -> &'a str {
+        self.message.source_name()
+    }
+
+    pub fn message_id(&self) -> i64 {
+        self.message.message_id()
+    }
+
+    /// The pulse times in ns since the epoch.
+    pub fn reference_time(&self) -> Vector<'a, i64> {
+        self.message.reference_time()
+    }
+
+    /// The index of the first event of each pulse.
+    pub fn reference_time_index(&self) -> Vector<'a, i32> {
+        self.message.reference_time_index()
     }
 
-    fn new(buf: &[u8]) -> Ev44 {
-        let source = Ev44::get_source(&buf);
-        let root = root_as_event_44_message(buf).unwrap();
-        let message_id = root.message_id();
-        let reference_time = root.reference_time();
-        dbg!(reference_time);
+    /// The event times in ns relative to their pulse.
+    pub fn time_of_flight(&self) -> Vector<'a, i32> {
+        self.message.time_of_flight().unwrap_or_default()
+    }
 
-        Ev44 {
-            source,
-            message_id,
-            reference_time,
+    /// The pixel ids of the events, which is empty for monitors.
+    pub fn pixel_id(&self) -> Vector<'a, i32> {
+        self.message.pixel_id().unwrap_or_default()
+    }
+
+    pub fn num_events(&self) -> usize {
+        self.time_of_flight().len()
+    }
+
+    /// Iterates over the events along with the time of the pulse they belong to.
+    pub fn events(&self) -> Events<'a> {
+        Events {
+            ev44: *self,
+            pulse: 0,
+            event: 0,
+        }
+    }
+
+    /// Copies the message so it can outlive the buffer it was read from.
+    pub fn to_owned_message(&self) -> OwnedEv44 {
+        OwnedEv44 {
+            buf: self.message._tab.buf().to_vec(),
         }
     }
 }
 
+/// An event from an ev44 message.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Event {
+    pub pulse_time: i64,
+    pub time_of_flight: i32,
+    pub pixel_id: Option<i32>,
+}
+
+pub struct Events<'a> {
+    ev44: Ev44<'a>,
+    pulse: usize,
+    event: usize,
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        let time_of_flight = self.ev44.time_of_flight();
+        if self.event >= time_of_flight.len() {
+            return None;
+        }
+        let index = self.ev44.reference_time_index();
+        while self.pulse + 1 < index.len() && index.get(self.pulse + 1) as usize <= self.event {
+            self.pulse += 1;
+        }
+        let pixel_id = self.ev44.pixel_id();
+        let event = Event {
+            pulse_time: self.ev44.reference_time().get(self.pulse),
+            time_of_flight: time_of_flight.get(self.event),
+            pixel_id: (!pixel_id.is_empty()).then(|| pixel_id.get(self.event)),
+        };
+        self.event += 1;
+        Some(event)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.ev44.num_events() - self.event;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Events<'_> {}
+
+/// A validated ev44 message which owns its flatbuffer, e.g. for passing between threads.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedEv44 {
+    buf: Vec<u8>,
+}
+
+impl OwnedEv44 {
+    pub fn new(buf: Vec<u8>) -> Result<OwnedEv44, Ev44Error> {
+        Ev44::from_bytes(&buf)?;
+        Ok(OwnedEv44 { buf })
+    }
+
+    pub fn view(&self) -> Ev44<'_> {
+        // The buffer was verified and checked when this was created.
+        let message = unsafe { root_as_event_44_message_unchecked(&self.buf) };
+        Ev44 { message }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ev44_events_generated::{Event44Message, Event44MessageArgs};
+    use crate::fixtures::Ev44Builder;
 
     use super::*;
 
-    fn create_flatbuffer() -> Vec<u8> {
-        let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
-        let source = builder.create_string("SomeSource");
-
-        let reference_times = vec![123456_i64];
-        let reference_time_offset = builder.create_vector(&reference_times);
-        let reference_index_offset = builder.create_vector(&vec![0_i32]);
-
-        let tof_offset = builder.create_vector(&vec![100, 200, 300]);
-        let pixels_offset = builder.create_vector(&vec![1, 2, 3]);
-
-        let ev44 = Event44Message::create(
-            &mut builder,
-            &Event44MessageArgs {
-                source_name: Some(source),
-                message_id: 123,
-                reference_time: Some(reference_time_offset),
-                reference_time_index: Some(reference_index_offset),
-                time_of_flight: Some(tof_offset),
-                pixel_id: Some(pixels_offset),
-            },
+    fn create_flatbuffer(reference_time_index: &[i32], tofs: &[i32], pixels: &[i32]) -> Vec<u8> {
+        let mut builder = Ev44Builder::new("SomeSource")
+            .message_id(123)
+            .pulse(123456, tofs, pixels);
+        for i in 1..reference_time_index.len() {
+            builder = builder.pulse(123456 + i as i64, &[], &[]);
+        }
+        builder.reference_time_index(reference_time_index).build()
+    }
+
+    #[test]
+    fn extract_ev44() {
+        let buf = create_flatbuffer(&[0], &[100, 200, 300], &[1, 2, 3]);
+
+        let ev44 = Ev44::from_bytes(&buf).unwrap();
+
+        assert_eq!(ev44.source_name(), "SomeSource");
+        assert_eq!(ev44.message_id(), 123);
+        assert_eq!(ev44.reference_time().iter().collect::<Vec<_>>(), [123456]);
+        assert_eq!(ev44.reference_time_index().iter().collect::<Vec<_>>(), [0]);
+        assert_eq!(
+            ev44.time_of_flight().iter().collect::<Vec<_>>(),
+            [100, 200, 300]
         );
-        builder.finish(ev44, Some("ev44"));
-        builder.finished_data().to_vec()
+        assert_eq!(ev44.pixel_id().iter().collect::<Vec<_>>(), [1, 2, 3]);
     }
 
     #[test]
-    fn can_get_source_from_ev44() {
-        let buf = create_flatbuffer();
+    fn invalid_flatbuffer_is_an_error() {
+        let result = Ev44::from_bytes(b"not a flatbuffer");
 
-        assert_eq!(Ev44::get_source(&buf.as_slice()), "SomeSource");
+        assert!(matches!(result, Err(Ev44Error::InvalidFlatbuffer(_))));
     }
 
     #[test]
-    fn extract_ev44() {
-        let buf = create_flatbuffer();
+    fn events_are_matched_to_their_pulse() {
+        let buf = create_flatbuffer(&[0, 2, 2], &[10, 20, 30], &[1, 2, 3]);
+        let ev44 = Ev44::from_bytes(&buf).unwrap();
+
+        let events: Vec<_> = ev44.events().collect();
+
+        assert_eq!(
+            events,
+            [
+                Event {
+                    pulse_time: 123456,
+                    time_of_flight: 10,
+                    pixel_id: Some(1)
+                },
+                Event {
+                    pulse_time: 123456,
+                    time_of_flight: 20,
+                    pixel_id: Some(2)
+                },
+                // The second pulse has no events.
+                Event {
+                    pulse_time: 123458,
+                    time_of_flight: 30,
+                    pixel_id: Some(3)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn monitor_events_have_no_pixels() {
+        let buf = create_flatbuffer(&[0], &[10, 20], &[]);
+        let ev44 = Ev44::from_bytes(&buf).unwrap();
+
+        let pixels: Vec<_> = ev44.events().map(|e| e.pixel_id).collect();
 
-        let ev44 = Ev44::new(&buf.as_slice());
+        assert_eq!(pixels, [None, None]);
+    }
+
+    #[test]
+    fn mismatched_event_lengths_is_an_error() {
+        let buf = create_flatbuffer(&[0], &[10, 20], &[1]);
+
+        assert_eq!(
+            Ev44::from_bytes(&buf).unwrap_err(),
+            Ev44Error::EventLengthMismatch {
+                time_of_flight: 2,
+                pixel_id: 1
+            }
+        );
+    }
+
+    #[test]
+    fn decreasing_index_is_an_error() {
+        let buf = create_flatbuffer(&[0, 2, 1], &[10, 20, 30], &[1, 2, 3]);
+
+        assert_eq!(
+            Ev44::from_bytes(&buf).unwrap_err(),
+            Ev44Error::IndexNotMonotonic { pulse: 2 }
+        );
+    }
+
+    #[test]
+    fn index_past_events_is_an_error() {
+        let buf = create_flatbuffer(&[0, 4], &[10, 20, 30], &[1, 2, 3]);
 
-        assert_eq!(ev44.source, "SomeSource");
-        assert_eq!(ev44.message_id, 123);
-        //assert_eq!(ev44.reference_time, vec![123456_i64]);
+        assert_eq!(
+            Ev44::from_bytes(&buf).unwrap_err(),
+            Ev44Error::IndexOutOfRange {
+                pulse: 1,
+                index: 4,
+                events: 3
+            }
+        );
+    }
+
+    #[test]
+    fn events_before_first_pulse_is_an_error() {
+        let buf = create_flatbuffer(&[1], &[10, 20], &[1, 2]);
+
+        assert_eq!(
+            Ev44::from_bytes(&buf).unwrap_err(),
+            Ev44Error::EventsBeforeFirstPulse
+        );
+    }
+
+    #[test]
+    fn owned_view_matches_borrowed() {
+        let buf = create_flatbuffer(&[0], &[100, 200], &[1, 2]);
+        let borrowed = Ev44::from_bytes(&buf).unwrap();
+
+        let owned = borrowed.to_owned_message();
+
+        assert_eq!(owned.as_bytes(), buf.as_slice());
+        assert_eq!(
+            owned.view().events().collect::<Vec<_>>(),
+            borrowed.events().collect::<Vec<_>>()
+        );
     }
 }
@@ -0,0 +1,414 @@
+use std::ffi::CString;
+
+use hdf5::types::{FloatSize, IntSize, TypeDescriptor};
+use hdf5::{LinkType, LocationType};
+use log::warn;
+use serde_json::{json, Number, Value};
+
+use crate::dump::{base_name, describe_attributes, read_string};
+use crate::json::FILE_ATTRIBUTES;
+use crate::nexus::{Dtype, MAX_DIMENSIONS};
+use crate::values::{nest, npy_f64, npy_i64};
+use crate::writers::f144;
+
+/// The topic given to guessed streaming modules, which has to be filled in by hand.
+pub const UNKNOWN_TOPIC: &str = "unknown";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportOptions {
+    /// Datasets with more values than this are written to `.npy` files instead of
+    /// inline.
+    pub max_inline_values: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            max_inline_values: 100,
+        }
+    }
+}
+
+/// A nexus_structure made from an existing file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Export {
+    pub structure: Value,
+    /// The `.npy` files referenced by the large datasets, by name.
+    pub files: Vec<(String, Vec<u8>)>,
+}
+
+/// A streaming module guessed from the layout of a group.
+#[derive(Clone, Debug, PartialEq)]
+enum Guess {
+    F144,
+    Ev44,
+}
+
+impl Guess {
+    /// The datasets the module writes, which aren't exported as static data.
+    fn datasets(&self) -> &'static [&'static str] {
+        match self {
+            Guess::F144 => &["time", "value", "cue_index", "cue_timestamp_zero"],
+            Guess::Ev44 => &[
+                "event_time_zero",
+                "event_index",
+                "event_time_offset",
+                "event_id",
+                "cue_index",
+                "cue_timestamp_zero",
+            ],
+        }
+    }
+}
+
+fn guess_module(nx_class: Option<&str>, datasets: &[String]) -> Option<Guess> {
+    let has = |name: &str| datasets.iter().any(|d| d == name);
+    match nx_class {
+        Some("NXlog") if has("time") && has("value") => Some(Guess::F144),
+        Some("NXevent_data") if has("event_time_zero") && has("event_time_offset") => {
+            Some(Guess::Ev44)
+        }
+        _ => None,
+    }
+}
+
+/// The dtype a static dataset of this type is written with, if it can be.
+fn static_dtype(descriptor: &TypeDescriptor) -> Option<Dtype> {
+    match descriptor {
+        TypeDescriptor::VarLenUnicode | TypeDescriptor::VarLenAscii => Some(Dtype::String),
+        TypeDescriptor::Float(FloatSize::U4) => Some(Dtype::Float),
+        TypeDescriptor::Float(_) => Some(Dtype::Double),
+        TypeDescriptor::Integer(IntSize::U8)
+        | TypeDescriptor::Unsigned(IntSize::U4 | IntSize::U8) => Some(Dtype::Int64),
+        TypeDescriptor::Integer(_) | TypeDescriptor::Unsigned(_) => Some(Dtype::Int32),
+        _ => None,
+    }
+}
+
+/// The name of the `.npy` file for the dataset at `path`.
+fn file_name(path: &str) -> String {
+    format!("{}.npy", path.trim_start_matches('/').replace('/', "_"))
+}
+
+/// Builds the nexus_structure which would produce a file like this one, i.e. the
+/// inverse of `json::generate_file_contents`.
+///
+/// The file's own attributes and any datasets next to the entries are included.
+/// Groups which look like they were written by a streaming module get a module
+/// with the group's name as the source, but the topic has to be filled in. Soft
+/// links become link modules, while external links, non-string attributes and
+/// anything which can't be written as a static dataset are skipped with a warning.
+pub fn export(file: &hdf5::File, options: &ExportOptions) -> hdf5::Result<Export> {
+    let mut exporter = Exporter {
+        options,
+        files: Vec::new(),
+    };
+    // The writer adds these itself, so copying them would stop them being updated.
+    let mut attributes = string_attributes(file)?;
+    attributes.retain(|a| {
+        a["name"]
+            .as_str()
+            .is_some_and(|name| !FILE_ATTRIBUTES.contains(&name))
+    });
+    let children = exporter.children(file, None)?;
+    let structure = if attributes.is_empty() {
+        json!({ "children": children })
+    } else {
+        json!({ "attributes": attributes, "children": children })
+    };
+    Ok(Export {
+        structure,
+        files: exporter.files,
+    })
+}
+
+struct Exporter<'a> {
+    options: &'a ExportOptions,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl Exporter<'_> {
+    fn group(&mut self, group: &hdf5::Group) -> hdf5::Result<Value> {
+        let attributes = string_attributes(group)?;
+        let nx_class = attributes
+            .iter()
+            .find(|a| a["name"] == "NX_class")
+            .and_then(|a| a["values"].as_str());
+        let children = self.children(group, nx_class)?;
+        Ok(json!({
+            "name": base_name(&group.name()),
+            "type": "group",
+            "attributes": attributes,
+            "children": children,
+        }))
+    }
+
+    fn children(
+        &mut self,
+        group: &hdf5::Group,
+        nx_class: Option<&str>,
+    ) -> hdf5::Result<Vec<Value>> {
+        // Going by the links, rather than the objects, means that what a soft link
+        // points to isn't exported twice and a dangling link isn't an error.
+        let members = group.iter_visit_default(Vec::new(), |_, name, info, members| {
+            members.push((name.to_owned(), info.link_type));
+            true
+        })?;
+        let mut groups = Vec::new();
+        let mut datasets = Vec::new();
+        let mut links = Vec::new();
+        for (name, link_type) in members {
+            let path = format!("{}/{name}", group.name().trim_end_matches('/'));
+            match link_type {
+                LinkType::Hard => match group.loc_type_by_name(&name)? {
+                    LocationType::Group => groups.push(group.group(&name)?),
+                    LocationType::Dataset => datasets.push(group.dataset(&name)?),
+                    _ => warn!("skipping {path} as it isn't a group or dataset"),
+                },
+                LinkType::Soft => links.push(json!({
+                    "module": "link",
+                    "config": { "name": name, "source": soft_link_target(group, &name)? },
+                })),
+                LinkType::External => warn!("skipping {path} as it is an external link"),
+            }
+        }
+        let names: Vec<_> = datasets.iter().map(|d| base_name(&d.name())).collect();
+        let guess = guess_module(nx_class, &names);
+
+        let mut children = Vec::new();
+        for child in groups {
+            children.push(self.group(&child)?);
+        }
+        for (dataset, name) in datasets.iter().zip(&names) {
+            if guess
+                .as_ref()
+                .is_some_and(|guess| guess.datasets().contains(&name.as_str()))
+            {
+                continue;
+            }
+            if let Some(child) = self.dataset(dataset, name)? {
+                children.push(child);
+            }
+        }
+        children.extend(links);
+        if let Some(guess) = guess {
+            children.push(module(&guess, group)?);
+        }
+        Ok(children)
+    }
+
+    fn dataset(&mut self, dataset: &hdf5::Dataset, name: &str) -> hdf5::Result<Option<Value>> {
+        let path = dataset.name();
+        let descriptor = dataset.dtype()?.to_descriptor()?;
+        let shape = dataset.shape();
+        let Some(dtype) = static_dtype(&descriptor) else {
+            warn!("skipping {path} as {descriptor} datasets aren't supported");
+            return Ok(None);
+        };
+        if shape.len() > MAX_DIMENSIONS {
+            warn!("skipping {path} as it has more than {MAX_DIMENSIONS} dimensions");
+            return Ok(None);
+        }
+        let mut config = json!({ "name": name, "dtype": dtype });
+        if dtype == Dtype::String {
+            let Some(value) = read_string(dataset, &descriptor) else {
+                warn!("skipping {path} as only single strings are supported");
+                return Ok(None);
+            };
+            config["values"] = Value::String(value);
+        } else {
+            let (numbers, npy) = if dtype == Dtype::Int32 || dtype == Dtype::Int64 {
+                let values = dataset.read_raw::<i64>()?;
+                let npy = npy_i64(&shape, &values);
+                (Some(values.into_iter().map(Number::from).collect()), npy)
+            } else {
+                let values = dataset.read_raw::<f64>()?;
+                let npy = npy_f64(&shape, &values);
+                // NaNs can't be written in JSON, so have to go in a file.
+                (values.into_iter().map(Number::from_f64).collect(), npy)
+            };
+            match numbers.filter(|n: &Vec<Number>| n.len() <= self.options.max_inline_values) {
+                Some(numbers) => config["values"] = nest(&shape, &numbers),
+                None => {
+                    let file = file_name(&path);
+                    config["file"] = Value::String(file.clone());
+                    self.files.push((file, npy));
+                }
+            }
+        }
+        Ok(Some(json!({
+            "module": "dataset",
+            "config": config,
+            "attributes": string_attributes(dataset)?,
+        })))
+    }
+}
+
+fn string_attributes(location: &hdf5::Location) -> hdf5::Result<Vec<Value>> {
+    Ok(describe_attributes(location)?
+        .into_iter()
+        .filter_map(|a| {
+            let Some(value) = a.value else {
+                warn!(
+                    "skipping attribute {} of {} as {} attributes aren't supported",
+                    a.name,
+                    location.name(),
+                    a.dtype
+                );
+                return None;
+            };
+            Some(json!({ "name": a.name, "dtype": "string", "values": value }))
+        })
+        .collect())
+}
+
+/// The path a soft link points to, as it was given when the link was created.
+fn soft_link_target(group: &hdf5::Group, name: &str) -> hdf5::Result<String> {
+    let c_name = CString::new(name).map_err(|err| err.to_string())?;
+    let mut buf = vec![0_u8; 256];
+    loop {
+        let result = hdf5::sync::sync(|| unsafe {
+            hdf5_sys::h5l::H5Lget_val(
+                group.id(),
+                c_name.as_ptr(),
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                hdf5_sys::h5p::H5P_DEFAULT,
+            )
+        });
+        if result < 0 {
+            return Err(format!("could not read the target of link {name}").into());
+        }
+        // The target is cut short if it doesn't fit, so try again with more room.
+        match buf.iter().position(|&b| b == 0) {
+            Some(end) if end + 1 < buf.len() => {
+                buf.truncate(end);
+                return String::from_utf8(buf).map_err(|err| err.to_string().into());
+            }
+            _ => buf.resize(buf.len() * 2, 0),
+        }
+    }
+}
+
+fn module(guess: &Guess, group: &hdf5::Group) -> hdf5::Result<Value> {
+    let source = base_name(&group.name());
+    Ok(match guess {
+        Guess::F144 => {
+            let value = group.dataset("value")?;
+            let dtype = value.dtype()?.to_descriptor()?.to_string();
+            let dtype = if f144::DTYPES.contains(&dtype.as_str()) {
+                dtype
+            } else {
+                "double".to_owned()
+            };
+            let mut config = json!({ "source": source, "topic": UNKNOWN_TOPIC, "dtype": dtype });
+            let units = describe_attributes(&value)?
+                .into_iter()
+                .find(|a| a.name == "units")
+                .and_then(|a| a.value);
+            if let Some(units) = units {
+                config["value_units"] = Value::String(units);
+            }
+            json!({ "module": "f144", "config": config })
+        }
+        Guess::Ev44 => json!({
+            "module": "ev44",
+            "config": { "source": source, "topic": UNKNOWN_TOPIC },
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::output::create_in_memory;
+
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn nxlog_with_time_and_value_is_f144() {
+        let guess = guess_module(Some("NXlog"), &names(&["time", "value"]));
+
+        assert_eq!(guess, Some(Guess::F144));
+    }
+
+    #[test]
+    fn nxevent_data_is_ev44() {
+        let datasets = names(&[
+            "event_id",
+            "event_index",
+            "event_time_offset",
+            "event_time_zero",
+        ]);
+
+        assert_eq!(
+            guess_module(Some("NXevent_data"), &datasets),
+            Some(Guess::Ev44)
+        );
+    }
+
+    #[test]
+    fn other_groups_have_no_module() {
+        assert_eq!(guess_module(Some("NXlog"), &names(&["value"])), None);
+        assert_eq!(guess_module(None, &names(&["time", "value"])), None);
+    }
+
+    #[test]
+    fn static_dtypes_are_widened() {
+        assert_eq!(
+            static_dtype(&TypeDescriptor::Unsigned(IntSize::U2)),
+            Some(Dtype::Int32)
+        );
+        assert_eq!(
+            static_dtype(&TypeDescriptor::Unsigned(IntSize::U4)),
+            Some(Dtype::Int64)
+        );
+        assert_eq!(
+            static_dtype(&TypeDescriptor::Float(FloatSize::U8)),
+            Some(Dtype::Double)
+        );
+        assert_eq!(static_dtype(&TypeDescriptor::Boolean), None);
+    }
+
+    #[test]
+    fn file_name_comes_from_the_path() {
+        assert_eq!(
+            file_name("/entry/instrument/detector/x_pixel_offset"),
+            "entry_instrument_detector_x_pixel_offset.npy"
+        );
+    }
+
+    #[test]
+    fn soft_links_are_exported_as_links() {
+        let file = create_in_memory().unwrap();
+        let entry = file.create_group("entry").unwrap();
+        entry
+            .new_dataset::<i64>()
+            .create("count")
+            .unwrap()
+            .write_scalar(&3_i64)
+            .unwrap();
+        entry.link_soft("/entry/count", "total").unwrap();
+        entry.link_soft("/entry/missing", "dangling").unwrap();
+
+        let export = export(&file, &ExportOptions::default()).unwrap();
+
+        let children = export.structure["children"][0]["children"]
+            .as_array()
+            .unwrap()
+            .clone();
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[0]["config"]["name"], "count");
+        assert!(children.contains(&json!({
+            "module": "link",
+            "config": {"name": "total", "source": "/entry/count"}
+        })));
+        assert!(children.contains(&json!({
+            "module": "link",
+            "config": {"name": "dangling", "source": "/entry/missing"}
+        })));
+    }
+}
@@ -0,0 +1,887 @@
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+use crate::ad00_area_detector_array_generated::{
+    ad00_ADArray, ad00_ADArrayArgs, Attribute, AttributeArgs, DType,
+};
+use crate::da00_dataarray_generated::{
+    da00_DataArray, da00_DataArrayArgs, da00_Variable, da00_VariableArgs, da00_dtype,
+};
+use crate::ev44_events_generated::{Event44Message, Event44MessageArgs};
+use crate::f144_logdata_generated::{self as f144, LogData, LogDataArgs};
+use crate::pl72_run_start_generated::{RunStart, RunStartArgs};
+use crate::run_stop_6s4t_generated::{RunStop, RunStopArgs};
+use crate::se00_data_generated::{
+    DoubleArray, DoubleArrayArgs, FloatArray, FloatArrayArgs, Int16Array, Int16ArrayArgs,
+    Int32Array, Int32ArrayArgs, Int64Array, Int64ArrayArgs, Int8Array, Int8ArrayArgs, Location,
+    SampleEnvironmentData, SampleEnvironmentDataArgs, UInt16Array, UInt16ArrayArgs, UInt32Array,
+    UInt32ArrayArgs, UInt64Array, UInt64ArrayArgs, UInt8Array, UInt8ArrayArgs, ValueUnion,
+};
+
+/// Numeric types which can be sent in the array based schemas.
+pub trait Element: bytemuck::Pod {
+    const AD00: DType;
+    const DA00: da00_dtype;
+
+    /// Creates the se00 union value holding these values.
+    fn se00_values(
+        builder: &mut FlatBufferBuilder,
+        values: &[Self],
+    ) -> (ValueUnion, WIPOffset<UnionWIPOffset>);
+}
+
+macro_rules! elements {
+    ($($t:ty => $dtype:ident, $array:ident($args:ident)),* $(,)?) => {
+        $(impl Element for $t {
+            const AD00: DType = DType::$dtype;
+            const DA00: da00_dtype = da00_dtype::$dtype;
+
+            fn se00_values(
+                builder: &mut FlatBufferBuilder,
+                values: &[$t],
+            ) -> (ValueUnion, WIPOffset<UnionWIPOffset>) {
+                let value = Some(builder.create_vector(values));
+                let array = $array::create(builder, &$args { value });
+                (ValueUnion::$array, array.as_union_value())
+            }
+        })*
+    };
+}
+
+elements! {
+    i8 => int8, Int8Array(Int8ArrayArgs),
+    u8 => uint8, UInt8Array(UInt8ArrayArgs),
+    i16 => int16, Int16Array(Int16ArrayArgs),
+    u16 => uint16, UInt16Array(UInt16ArrayArgs),
+    i32 => int32, Int32Array(Int32ArrayArgs),
+    u32 => uint32, UInt32Array(UInt32ArrayArgs),
+    i64 => int64, Int64Array(Int64ArrayArgs),
+    u64 => uint64, UInt64Array(UInt64ArrayArgs),
+    f32 => float32, FloatArray(FloatArrayArgs),
+    f64 => float64, DoubleArray(DoubleArrayArgs),
+}
+
+/// The raw bytes of the values, as sent in ad00 and da00.
+///
+/// Flatbuffers are little-endian, which is assumed here.
+fn to_bytes<T: Element>(values: &[T]) -> Vec<u8> {
+    bytemuck::cast_slice(values).to_vec()
+}
+
+fn create_optional_string<'a>(
+    builder: &mut FlatBufferBuilder<'a>,
+    value: &Option<String>,
+) -> Option<WIPOffset<&'a str>> {
+    value.as_deref().map(|v| builder.create_string(v))
+}
+
+/// Builds ev44 event messages.
+///
+/// E.g. `Ev44Builder::new("detector").pulse(t, &tofs, &pixels).build()`
+#[derive(Clone, Debug, Default)]
+pub struct Ev44Builder {
+    source: String,
+    message_id: i64,
+    reference_time: Vec<i64>,
+    reference_time_index: Vec<i32>,
+    time_of_flight: Vec<i32>,
+    pixel_id: Vec<i32>,
+}
+
+impl Ev44Builder {
+    pub fn new(source: &str) -> Ev44Builder {
+        Ev44Builder {
+            source: source.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn message_id(mut self, message_id: i64) -> Ev44Builder {
+        self.message_id = message_id;
+        self
+    }
+
+    /// Adds a pulse at `time` (ns since the epoch) along with its events.
+    pub fn pulse(mut self, time: i64, tofs: &[i32], pixels: &[i32]) -> Ev44Builder {
+        self.reference_time.push(time);
+        self.reference_time_index
+            .push(self.time_of_flight.len() as i32);
+        self.time_of_flight.extend_from_slice(tofs);
+        self.pixel_id.extend_from_slice(pixels);
+        self
+    }
+
+    /// Replaces the pulse indexes, e.g. to create an invalid message.
+    pub fn reference_time_index(mut self, index: &[i32]) -> Ev44Builder {
+        self.reference_time_index = index.to_vec();
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let capacity = 1024
+            + 8 * self.reference_time.len()
+            + 4 * (self.reference_time_index.len()
+                + self.time_of_flight.len()
+                + self.pixel_id.len());
+        let mut builder = FlatBufferBuilder::with_capacity(capacity);
+        let source_name = Some(builder.create_string(&self.source));
+        let reference_time = Some(builder.create_vector(&self.reference_time));
+        let reference_time_index = Some(builder.create_vector(&self.reference_time_index));
+        let time_of_flight = Some(builder.create_vector(&self.time_of_flight));
+        let pixel_id = Some(builder.create_vector(&self.pixel_id));
+        let ev44 = Event44Message::create(
+            &mut builder,
+            &Event44MessageArgs {
+                source_name,
+                message_id: self.message_id,
+                reference_time,
+                reference_time_index,
+                time_of_flight,
+                pixel_id,
+            },
+        );
+        builder.finish(ev44, Some("ev44"));
+        builder.finished_data().to_vec()
+    }
+}
+
+macro_rules! f144_values {
+    (
+        scalars { $($scalar:ident($st:ty) = $sargs:ident),* $(,)? }
+        arrays { $($array:ident($at:ty) = $aargs:ident),* $(,)? }
+    ) => {
+        /// A value for an f144 message, one variant per type in the schema's union.
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum F144Value {
+            $($scalar($st),)*
+            $($array(Vec<$at>),)*
+        }
+
+        $(impl From<$st> for F144Value {
+            fn from(value: $st) -> Self {
+                F144Value::$scalar(value)
+            }
+        })*
+
+        $(impl From<Vec<$at>> for F144Value {
+            fn from(value: Vec<$at>) -> Self {
+                F144Value::$array(value)
+            }
+        })*
+
+        impl F144Value {
+            fn create(
+                &self,
+                builder: &mut FlatBufferBuilder,
+            ) -> (f144::Value, WIPOffset<UnionWIPOffset>) {
+                match self {
+                    $(F144Value::$scalar(value) => {
+                        let value = f144::$scalar::create(builder, &f144::$sargs { value: *value });
+                        (f144::Value::$scalar, value.as_union_value())
+                    })*
+                    $(F144Value::$array(values) => {
+                        let value = Some(builder.create_vector(values));
+                        let value = f144::$array::create(builder, &f144::$aargs { value });
+                        (f144::Value::$array, value.as_union_value())
+                    })*
+                }
+            }
+        }
+    };
+}
+
+f144_values! {
+    scalars {
+        Byte(i8) = ByteArgs,
+        UByte(u8) = UByteArgs,
+        Short(i16) = ShortArgs,
+        UShort(u16) = UShortArgs,
+        Int(i32) = IntArgs,
+        UInt(u32) = UIntArgs,
+        Long(i64) = LongArgs,
+        ULong(u64) = ULongArgs,
+        Float(f32) = FloatArgs,
+        Double(f64) = DoubleArgs,
+    }
+    arrays {
+        ArrayByte(i8) = ArrayByteArgs,
+        ArrayUByte(u8) = ArrayUByteArgs,
+        ArrayShort(i16) = ArrayShortArgs,
+        ArrayUShort(u16) = ArrayUShortArgs,
+        ArrayInt(i32) = ArrayIntArgs,
+        ArrayUInt(u32) = ArrayUIntArgs,
+        ArrayLong(i64) = ArrayLongArgs,
+        ArrayULong(u64) = ArrayULongArgs,
+        ArrayFloat(f32) = ArrayFloatArgs,
+        ArrayDouble(f64) = ArrayDoubleArgs,
+    }
+}
+
+/// Builds f144 log data messages.
+///
+/// E.g. `F144Builder::new("temperature", 1.5).timestamp(t).build()`
+#[derive(Clone, Debug)]
+pub struct F144Builder {
+    source: String,
+    value: F144Value,
+    timestamp: i64,
+}
+
+impl F144Builder {
+    pub fn new<V: Into<F144Value>>(source: &str, value: V) -> F144Builder {
+        F144Builder {
+            source: source.to_owned(),
+            value: value.into(),
+            timestamp: 0,
+        }
+    }
+
+    /// The time of the value in ns since the epoch.
+    pub fn timestamp(mut self, timestamp: i64) -> F144Builder {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let source_name = Some(builder.create_string(&self.source));
+        let (value_type, value) = self.value.create(&mut builder);
+        let f144 = LogData::create(
+            &mut builder,
+            &LogDataArgs {
+                source_name,
+                value_type,
+                value: Some(value),
+                timestamp: self.timestamp,
+            },
+        );
+        builder.finish(f144, Some("f144"));
+        builder.finished_data().to_vec()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Ad00Attribute {
+    name: String,
+    description: String,
+    data_type: DType,
+    data: Vec<u8>,
+}
+
+/// Builds ad00 area detector images.
+///
+/// E.g. `Ad00Builder::new("camera", &[2, 2], &[1_u16, 2, 3, 4]).timestamp(t).build()`
+#[derive(Clone, Debug)]
+pub struct Ad00Builder {
+    source: String,
+    id: i32,
+    timestamp: i64,
+    dimensions: Vec<i64>,
+    data_type: DType,
+    data: Vec<u8>,
+    attributes: Vec<Ad00Attribute>,
+}
+
+impl Ad00Builder {
+    pub fn new<T: Element>(source: &str, dimensions: &[i64], values: &[T]) -> Ad00Builder {
+        Ad00Builder {
+            source: source.to_owned(),
+            id: 0,
+            timestamp: 0,
+            dimensions: dimensions.to_vec(),
+            data_type: T::AD00,
+            data: to_bytes(values),
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn id(mut self, id: i32) -> Ad00Builder {
+        self.id = id;
+        self
+    }
+
+    /// The time of the image in ns since the epoch.
+    pub fn timestamp(mut self, timestamp: i64) -> Ad00Builder {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn attribute<T: Element>(
+        mut self,
+        name: &str,
+        description: &str,
+        values: &[T],
+    ) -> Ad00Builder {
+        self.attributes.push(Ad00Attribute {
+            name: name.to_owned(),
+            description: description.to_owned(),
+            data_type: T::AD00,
+            data: to_bytes(values),
+        });
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024 + self.data.len());
+        let attributes: Vec<_> = self
+            .attributes
+            .iter()
+            .map(|a| {
+                let name = Some(builder.create_string(&a.name));
+                let description = Some(builder.create_string(&a.description));
+                let data = Some(builder.create_vector(&a.data));
+                Attribute::create(
+                    &mut builder,
+                    &AttributeArgs {
+                        name,
+                        description,
+                        source: None,
+                        data_type: a.data_type,
+                        data,
+                    },
+                )
+            })
+            .collect();
+        let attributes = Some(builder.create_vector(&attributes));
+        let source_name = Some(builder.create_string(&self.source));
+        let dimensions = Some(builder.create_vector(&self.dimensions));
+        let data = Some(builder.create_vector(&self.data));
+        let ad00 = ad00_ADArray::create(
+            &mut builder,
+            &ad00_ADArrayArgs {
+                source_name,
+                id: self.id,
+                timestamp: self.timestamp,
+                dimensions,
+                data_type: self.data_type,
+                data,
+                attributes,
+            },
+        );
+        builder.finish(ad00, Some("ad00"));
+        builder.finished_data().to_vec()
+    }
+}
+
+/// A variable in a da00 message.
+///
+/// E.g. `Da00Variable::new("signal", &[3], &[1_i32, 2, 3]).unit("counts").axes(&["x"])`
+#[derive(Clone, Debug)]
+pub struct Da00Variable {
+    name: String,
+    unit: Option<String>,
+    label: Option<String>,
+    axes: Vec<String>,
+    shape: Vec<i64>,
+    data_type: da00_dtype,
+    data: Vec<u8>,
+}
+
+impl Da00Variable {
+    pub fn new<T: Element>(name: &str, shape: &[i64], values: &[T]) -> Da00Variable {
+        Da00Variable {
+            name: name.to_owned(),
+            unit: None,
+            label: None,
+            axes: Vec::new(),
+            shape: shape.to_vec(),
+            data_type: T::DA00,
+            data: to_bytes(values),
+        }
+    }
+
+    pub fn unit(mut self, unit: &str) -> Da00Variable {
+        self.unit = Some(unit.to_owned());
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Da00Variable {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn axes(mut self, axes: &[&str]) -> Da00Variable {
+        self.axes = axes.iter().map(|a| a.to_string()).collect();
+        self
+    }
+}
+
+/// Builds da00 data array messages.
+#[derive(Clone, Debug)]
+pub struct Da00Builder {
+    source: String,
+    timestamp: i64,
+    variables: Vec<Da00Variable>,
+}
+
+impl Da00Builder {
+    pub fn new(source: &str) -> Da00Builder {
+        Da00Builder {
+            source: source.to_owned(),
+            timestamp: 0,
+            variables: Vec::new(),
+        }
+    }
+
+    /// The time of the data in ns since the epoch.
+    pub fn timestamp(mut self, timestamp: i64) -> Da00Builder {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn variable(mut self, variable: Da00Variable) -> Da00Builder {
+        self.variables.push(variable);
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let variables: Vec<_> = self
+            .variables
+            .iter()
+            .map(|v| {
+                let name = Some(builder.create_string(&v.name));
+                let unit = create_optional_string(&mut builder, &v.unit);
+                let label = create_optional_string(&mut builder, &v.label);
+                let axes: Vec<_> = v.axes.iter().map(|a| builder.create_string(a)).collect();
+                let axes = Some(builder.create_vector(&axes));
+                let shape = Some(builder.create_vector(&v.shape));
+                let data = Some(builder.create_vector(&v.data));
+                da00_Variable::create(
+                    &mut builder,
+                    &da00_VariableArgs {
+                        name,
+                        unit,
+                        label,
+                        source: None,
+                        data_type: v.data_type,
+                        axes,
+                        shape,
+                        data,
+                    },
+                )
+            })
+            .collect();
+        let data = Some(builder.create_vector(&variables));
+        let source_name = Some(builder.create_string(&self.source));
+        let da00 = da00_DataArray::create(
+            &mut builder,
+            &da00_DataArrayArgs {
+                source_name,
+                timestamp: self.timestamp,
+                data,
+            },
+        );
+        builder.finish(da00, Some("da00"));
+        builder.finished_data().to_vec()
+    }
+}
+
+/// Builds se00 sample environment messages.
+///
+/// E.g. `Se00Builder::new("chopper", &[1.0, 2.0]).packet_timestamp(t).time_delta(10.0).build()`
+#[derive(Clone, Debug)]
+pub struct Se00Builder<T: Element> {
+    name: String,
+    channel: i32,
+    packet_timestamp: i64,
+    time_delta: f64,
+    timestamp_location: Location,
+    values: Vec<T>,
+    timestamps: Option<Vec<i64>>,
+    message_counter: i64,
+}
+
+impl<T: Element> Se00Builder<T> {
+    pub fn new(name: &str, values: &[T]) -> Se00Builder<T> {
+        Se00Builder {
+            name: name.to_owned(),
+            channel: 0,
+            packet_timestamp: 0,
+            time_delta: 0.0,
+            timestamp_location: Location::Unknown,
+            values: values.to_vec(),
+            timestamps: None,
+            message_counter: 0,
+        }
+    }
+
+    pub fn channel(mut self, channel: i32) -> Se00Builder<T> {
+        self.channel = channel;
+        self
+    }
+
+    /// The time of the first value in ns since the epoch.
+    pub fn packet_timestamp(mut self, timestamp: i64) -> Se00Builder<T> {
+        self.packet_timestamp = timestamp;
+        self
+    }
+
+    /// The time between values in ns, used if there are no explicit timestamps.
+    pub fn time_delta(mut self, time_delta: f64) -> Se00Builder<T> {
+        self.time_delta = time_delta;
+        self
+    }
+
+    pub fn timestamp_location(mut self, location: Location) -> Se00Builder<T> {
+        self.timestamp_location = location;
+        self
+    }
+
+    pub fn timestamps(mut self, timestamps: &[i64]) -> Se00Builder<T> {
+        self.timestamps = Some(timestamps.to_vec());
+        self
+    }
+
+    pub fn message_counter(mut self, counter: i64) -> Se00Builder<T> {
+        self.message_counter = counter;
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let name = Some(builder.create_string(&self.name));
+        let (values_type, values) = T::se00_values(&mut builder, &self.values);
+        let timestamps = self.timestamps.as_ref().map(|t| builder.create_vector(t));
+        let se00 = SampleEnvironmentData::create(
+            &mut builder,
+            &SampleEnvironmentDataArgs {
+                name,
+                channel: self.channel,
+                packet_timestamp: self.packet_timestamp,
+                time_delta: self.time_delta,
+                timestamp_location: self.timestamp_location,
+                values_type,
+                values: Some(values),
+                timestamps,
+                message_counter: self.message_counter,
+            },
+        );
+        builder.finish(se00, Some("se00"));
+        builder.finished_data().to_vec()
+    }
+}
+
+/// Builds pl72 run start commands.
+///
+/// E.g. `RunStartBuilder::new("job").filename("out.nxs").nexus_structure(&json).build()`
+#[derive(Clone, Debug, Default)]
+pub struct RunStartBuilder {
+    job_id: String,
+    start_time: u64,
+    stop_time: u64,
+    run_name: Option<String>,
+    instrument_name: Option<String>,
+    nexus_structure: Option<String>,
+    broker: Option<String>,
+    service_id: Option<String>,
+    filename: Option<String>,
+    metadata: Option<String>,
+    control_topic: Option<String>,
+}
+
+impl RunStartBuilder {
+    pub fn new(job_id: &str) -> RunStartBuilder {
+        RunStartBuilder {
+            job_id: job_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// In ms since the epoch.
+    pub fn start_time(mut self, start_time: u64) -> RunStartBuilder {
+        self.start_time = start_time;
+        self
+    }
+
+    /// In ms since the epoch, where 0 means no stop time.
+    pub fn stop_time(mut self, stop_time: u64) -> RunStartBuilder {
+        self.stop_time = stop_time;
+        self
+    }
+
+    pub fn run_name(mut self, run_name: &str) -> RunStartBuilder {
+        self.run_name = Some(run_name.to_owned());
+        self
+    }
+
+    pub fn instrument_name(mut self, instrument_name: &str) -> RunStartBuilder {
+        self.instrument_name = Some(instrument_name.to_owned());
+        self
+    }
+
+    pub fn nexus_structure(mut self, nexus_structure: &str) -> RunStartBuilder {
+        self.nexus_structure = Some(nexus_structure.to_owned());
+        self
+    }
+
+    pub fn broker(mut self, broker: &str) -> RunStartBuilder {
+        self.broker = Some(broker.to_owned());
+        self
+    }
+
+    pub fn service_id(mut self, service_id: &str) -> RunStartBuilder {
+        self.service_id = Some(service_id.to_owned());
+        self
+    }
+
+    pub fn filename(mut self, filename: &str) -> RunStartBuilder {
+        self.filename = Some(filename.to_owned());
+        self
+    }
+
+    pub fn metadata(mut self, metadata: &str) -> RunStartBuilder {
+        self.metadata = Some(metadata.to_owned());
+        self
+    }
+
+    pub fn control_topic(mut self, control_topic: &str) -> RunStartBuilder {
+        self.control_topic = Some(control_topic.to_owned());
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let job_id = Some(builder.create_string(&self.job_id));
+        let run_name = create_optional_string(&mut builder, &self.run_name);
+        let instrument_name = create_optional_string(&mut builder, &self.instrument_name);
+        let nexus_structure = create_optional_string(&mut builder, &self.nexus_structure);
+        let broker = create_optional_string(&mut builder, &self.broker);
+        let service_id = create_optional_string(&mut builder, &self.service_id);
+        let filename = create_optional_string(&mut builder, &self.filename);
+        let metadata = create_optional_string(&mut builder, &self.metadata);
+        let control_topic = create_optional_string(&mut builder, &self.control_topic);
+        let pl72 = RunStart::create(
+            &mut builder,
+            &RunStartArgs {
+                start_time: self.start_time,
+                stop_time: self.stop_time,
+                run_name,
+                instrument_name,
+                nexus_structure,
+                job_id,
+                broker,
+                service_id,
+                filename,
+                n_periods: 1,
+                detector_spectrum_map: None,
+                metadata,
+                control_topic,
+            },
+        );
+        builder.finish(pl72, Some("pl72"));
+        builder.finished_data().to_vec()
+    }
+}
+
+/// Builds 6s4t run stop commands.
+#[derive(Clone, Debug, Default)]
+pub struct RunStopBuilder {
+    job_id: String,
+    stop_time: u64,
+    run_name: Option<String>,
+    service_id: Option<String>,
+    command_id: Option<String>,
+}
+
+impl RunStopBuilder {
+    pub fn new(job_id: &str) -> RunStopBuilder {
+        RunStopBuilder {
+            job_id: job_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// In ms since the epoch, where 0 means stop now.
+    pub fn stop_time(mut self, stop_time: u64) -> RunStopBuilder {
+        self.stop_time = stop_time;
+        self
+    }
+
+    pub fn run_name(mut self, run_name: &str) -> RunStopBuilder {
+        self.run_name = Some(run_name.to_owned());
+        self
+    }
+
+    pub fn service_id(mut self, service_id: &str) -> RunStopBuilder {
+        self.service_id = Some(service_id.to_owned());
+        self
+    }
+
+    pub fn command_id(mut self, command_id: &str) -> RunStopBuilder {
+        self.command_id = Some(command_id.to_owned());
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut builder = FlatBufferBuilder::with_capacity(1024);
+        let job_id = Some(builder.create_string(&self.job_id));
+        let run_name = create_optional_string(&mut builder, &self.run_name);
+        let service_id = create_optional_string(&mut builder, &self.service_id);
+        let command_id = create_optional_string(&mut builder, &self.command_id);
+        let stop = RunStop::create(
+            &mut builder,
+            &RunStopArgs {
+                stop_time: self.stop_time,
+                run_name,
+                job_id,
+                service_id,
+                command_id,
+            },
+        );
+        builder.finish(stop, Some("6s4t"));
+        builder.finished_data().to_vec()
+    }
+}
+
+/// A directory under the system's temp dir for a test, with a unique name so tests
+/// can run in parallel, which is removed along with its contents when dropped.
+#[cfg(test)]
+pub struct TempDir(std::path::PathBuf);
+
+#[cfg(test)]
+impl TempDir {
+    pub fn new(name: &str) -> TempDir {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "filewriter_{name}_{}_{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+}
+
+#[cfg(test)]
+impl std::ops::Deref for TempDir {
+    type Target = std::path::Path;
+
+    fn deref(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl AsRef<std::path::Path> for TempDir {
+    fn as_ref(&self) -> &std::path::Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ad00_area_detector_array_generated::root_as_ad_00_adarray;
+    use crate::da00_dataarray_generated::root_as_da_00_data_array;
+    use crate::ev44::Ev44;
+    use crate::f144_logdata_generated::root_as_log_data;
+    use crate::pl72_run_start_generated::root_as_run_start;
+    use crate::run_stop_6s4t_generated::root_as_run_stop;
+    use crate::se00_data_generated::root_as_sample_environment_data;
+
+    use super::*;
+
+    #[test]
+    fn ev44_pulses_are_indexed_by_event() {
+        let buf = Ev44Builder::new("detector")
+            .pulse(1000, &[10, 20], &[1, 2])
+            .pulse(2000, &[30], &[3])
+            .build();
+
+        let ev44 = Ev44::from_bytes(&buf).unwrap();
+
+        assert_eq!(ev44.source_name(), "detector");
+        assert_eq!(
+            ev44.reference_time().iter().collect::<Vec<_>>(),
+            [1000, 2000]
+        );
+        assert_eq!(
+            ev44.reference_time_index().iter().collect::<Vec<_>>(),
+            [0, 2]
+        );
+        assert_eq!(ev44.num_events(), 3);
+    }
+
+    #[test]
+    fn f144_array_value() {
+        let buf = F144Builder::new("motor", vec![1.0, 2.0])
+            .timestamp(123)
+            .build();
+
+        let f144 = root_as_log_data(&buf).unwrap();
+
+        assert_eq!(f144.source_name(), "motor");
+        assert_eq!(f144.timestamp(), 123);
+        let values = f144.value_as_array_double().unwrap().value().unwrap();
+        assert_eq!(values.iter().collect::<Vec<_>>(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn ad00_data_is_sent_as_bytes() {
+        let buf = Ad00Builder::new("camera", &[2, 2], &[1_u16, 2, 3, 4])
+            .attribute("exposure", "exposure time", &[0.5_f64])
+            .build();
+
+        let ad00 = root_as_ad_00_adarray(&buf).unwrap();
+
+        assert_eq!(ad00.data_type(), DType::uint16);
+        assert_eq!(ad00.dimensions().iter().collect::<Vec<_>>(), [2, 2]);
+        assert_eq!(ad00.data().bytes(), to_bytes(&[1_u16, 2, 3, 4]));
+        assert_eq!(ad00.attributes().unwrap().get(0).name(), "exposure");
+    }
+
+    #[test]
+    fn da00_variables() {
+        let buf = Da00Builder::new("histogram")
+            .variable(Da00Variable::new("signal", &[3], &[1_i32, 2, 3]).axes(&["x"]))
+            .variable(Da00Variable::new("x", &[3], &[0.1_f32, 0.2, 0.3]).unit("m"))
+            .build();
+
+        let da00 = root_as_da_00_data_array(&buf).unwrap();
+
+        let variables = da00.data();
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables.get(0).data_type(), da00_dtype::int32);
+        assert_eq!(variables.get(0).axes().unwrap().get(0), "x");
+        assert_eq!(variables.get(1).unit(), Some("m"));
+    }
+
+    #[test]
+    fn se00_values_use_matching_union_type() {
+        let buf = Se00Builder::new("chopper", &[1_i16, 2])
+            .timestamps(&[10, 20])
+            .build();
+
+        let se00 = root_as_sample_environment_data(&buf).unwrap();
+
+        assert_eq!(se00.values_type(), ValueUnion::Int16Array);
+        assert_eq!(se00.timestamps().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn run_start_and_stop() {
+        let start = RunStartBuilder::new("job")
+            .start_time(1000)
+            .filename("file.nxs")
+            .build();
+        let stop = RunStopBuilder::new("job").stop_time(2000).build();
+
+        let start = root_as_run_start(&start).unwrap();
+        let stop = root_as_run_stop(&stop).unwrap();
+
+        assert_eq!(start.job_id(), Some("job"));
+        assert_eq!(start.start_time(), 1000);
+        assert_eq!(start.filename(), Some("file.nxs"));
+        assert_eq!(start.n_periods(), 1);
+        assert_eq!(stop.job_id(), Some("job"));
+        assert_eq!(stop.stop_time(), 2000);
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+
+use log::warn;
+
+use crate::journal::{journal_path, Journal};
+use crate::json::ModuleSettings;
+use crate::output::OutputFile;
+use crate::process::{DecodedMessage, Source, SourceKey, Streamer, WallClock};
+use crate::stats::{write_attributes, JobStats};
+use crate::writers::{create_writer, FlushPolicy, WriteError, Writer};
+
+/// How long the writer thread waits for a message before checking whether
+/// anything needs flushing.
+const FLUSH_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long a consumer waits before trying again once its topic has finished, or
+/// after an empty poll of a source which doesn't block.
+const CONSUMER_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Settings for how a job's threads pass data around.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobOptions {
+    /// How many decoded messages can be waiting for the writer thread before the
+    /// consumers have to wait.
+    pub channel_capacity: usize,
+    pub flush_policy: FlushPolicy,
+    /// Records each source's statistics as attributes on its module's group when
+    /// the file is closed.
+    pub stats_attributes: bool,
+}
+
+impl Default for JobOptions {
+    fn default() -> Self {
+        JobOptions {
+            channel_capacity: 1000,
+            flush_policy: FlushPolicy::default(),
+            stats_attributes: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum JobError {
+    Hdf5(hdf5::Error),
+    /// Couldn't create the consumer for a topic.
+    Connect {
+        topic: String,
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for JobError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobError::Hdf5(err) => write!(f, "HDF5 error: {err}"),
+            JobError::Connect { topic, reason } => {
+                write!(f, "could not consume from {topic}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JobError {}
+
+impl From<hdf5::Error> for JobError {
+    fn from(err: hdf5::Error) -> Self {
+        JobError::Hdf5(err)
+    }
+}
+
+/// A stop time which can be changed while a job is running, until every one of its
+/// topics has finished and the file is being closed.
+#[derive(Clone, Debug, Default)]
+pub struct StopTime(Arc<Mutex<StopState>>);
+
+#[derive(Debug, Default)]
+struct StopState {
+    stop_time: Option<SystemTime>,
+    /// Whether each topic has nothing more to write before the stop time.
+    topics: Vec<bool>,
+    closing: bool,
+}
+
+impl StopTime {
+    pub fn new(stop_time: Option<SystemTime>, topics: usize) -> StopTime {
+        StopTime(Arc::new(Mutex::new(StopState {
+            stop_time,
+            topics: vec![false; topics],
+            closing: false,
+        })))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StopState> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn get(&self) -> Option<SystemTime> {
+        self.lock().stop_time
+    }
+
+    /// Returns false if it's too late to change, as the file is being closed.
+    pub fn set(&self, stop_time: SystemTime) -> bool {
+        let mut state = self.lock();
+        if state.closing {
+            return false;
+        }
+        state.stop_time = Some(stop_time);
+        // Every topic has to check again against the new stop time.
+        state
+            .topics
+            .iter_mut()
+            .for_each(|finished| *finished = false);
+        true
+    }
+
+    /// Records whether a topic has finished with the given stop time, which is
+    /// ignored if it has changed since.
+    ///
+    /// Returns true once every topic has finished, so the file can be closed.
+    fn topic_finished(&self, topic: usize, stop_time: Option<SystemTime>, finished: bool) -> bool {
+        let mut state = self.lock();
+        if !state.closing && state.stop_time == stop_time {
+            state.topics[topic] = finished;
+            state.closing = state.topics.iter().all(|finished| *finished);
+        }
+        state.closing
+    }
+}
+
+/// A running write job.
+///
+/// Each topic is consumed on its own thread. The consumers pass decoded messages
+/// over a bounded channel to a single writer thread which owns the file, as HDF5
+/// is not thread-safe. If the writer falls behind, the channel fills up and the
+/// consumers block until it catches up.
+///
+/// Whenever the file is flushed the writer thread records the lengths of the
+/// streamed datasets in a [`Journal`] next to the file, which is removed once the
+/// file has been closed.
+///
+/// Messages which can't be parsed are dropped rather than failing the job; they are
+/// counted in the job's [`JobStats`] along with what was received and written.
+pub struct Job {
+    consumers: Vec<JoinHandle<()>>,
+    writer: JoinHandle<Result<PathBuf, WriteError>>,
+    stop_time: StopTime,
+    interrupted: Arc<AtomicBool>,
+    stats: JobStats,
+}
+
+impl Job {
+    /// Creates the writers for the modules and starts streaming into the file.
+    ///
+    /// `connect` is called once for each topic to create the consumer for it. All the
+    /// consumers are created before any data is written, so a job which can't get
+    /// its data fails straight away.
+    pub fn start<F, E>(
+        mut file: OutputFile,
+        modules: &[ModuleSettings],
+        start_time: SystemTime,
+        stop_time: Option<SystemTime>,
+        options: JobOptions,
+        mut connect: F,
+    ) -> Result<Job, JobError>
+    where
+        F: FnMut(&str) -> Result<Box<dyn Source + Send>, E>,
+        E: std::fmt::Display,
+    {
+        let mut writers = HashMap::new();
+        let mut groups = HashMap::new();
+        let mut topics: HashMap<&str, Vec<SourceKey>> = HashMap::new();
+        for module in modules {
+            let writer = create_writer(&file, module, options.flush_policy.clone())?;
+            writers.insert(module.source_key(), writer);
+            if options.stats_attributes {
+                groups.insert(module.source_key(), module.path().to_owned());
+            }
+            topics
+                .entry(module.topic())
+                .or_default()
+                .push(module.source_key());
+        }
+        let mut sources = Vec::with_capacity(topics.len());
+        for (topic, keys) in topics {
+            let source = connect(topic).map_err(|err| JobError::Connect {
+                topic: topic.to_owned(),
+                reason: err.to_string(),
+            })?;
+            sources.push((source, keys));
+        }
+        // All the objects must exist before switching to SWMR mode.
+        file.start_streaming()?;
+
+        let stats = JobStats::new(writers.keys().cloned());
+        let (sender, receiver) = sync_channel(options.channel_capacity);
+        let writer = {
+            let stats = stats.clone();
+            thread::spawn(move || run_writer(file, writers, groups, receiver, stats))
+        };
+
+        let stop_time = StopTime::new(stop_time, sources.len());
+        let interrupted = Arc::new(AtomicBool::new(false));
+        let consumers = sources
+            .into_iter()
+            .enumerate()
+            .map(|(topic, (mut source, keys))| {
+                let sender = sender.clone();
+                let stop_time = stop_time.clone();
+                let interrupted = interrupted.clone();
+                let stats = stats.clone();
+                thread::spawn(move || {
+                    let mut streamer = Streamer::new(keys).with_stats(stats);
+                    while !interrupted.load(Ordering::Relaxed) {
+                        let stop = stop_time.get();
+                        let finished = streamer.process(
+                            source.as_mut(),
+                            &start_time,
+                            stop,
+                            &sender,
+                            &WallClock,
+                        );
+                        if streamer.is_disconnected()
+                            || stop_time.topic_finished(topic, stop, finished)
+                        {
+                            break;
+                        }
+                        // A finished topic waits in case the stop time is moved later.
+                        if finished || (streamer.is_idle() && !source.blocks()) {
+                            thread::sleep(CONSUMER_BACKOFF);
+                        }
+                    }
+                    streamer.close();
+                })
+            })
+            .collect();
+
+        Ok(Job {
+            consumers,
+            writer,
+            stop_time,
+            interrupted,
+            stats,
+        })
+    }
+
+    /// What has happened to each source's messages so far.
+    pub fn stats(&self) -> &JobStats {
+        &self.stats
+    }
+
+    /// Stops reading any more data, e.g. when the process is shutting down. Whatever
+    /// has already been read is still written and the file closed as normal.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Changes when the job stops; it can be earlier or later than before, or
+    /// already in the past.
+    ///
+    /// Returns false without changing anything once every topic has finished, as
+    /// the file is then being closed.
+    pub fn set_stop_time(&self, stop_time: SystemTime) -> bool {
+        self.stop_time.set(stop_time)
+    }
+
+    /// Whether the file has been closed, either because the job is done or it failed.
+    pub fn is_finished(&self) -> bool {
+        self.writer.is_finished()
+    }
+
+    /// Waits for all the consumers to finish and the file to be closed, returning
+    /// the file's final path.
+    pub fn join(self) -> Result<PathBuf, WriteError> {
+        for consumer in self.consumers {
+            if let Err(panic) = consumer.join() {
+                std::panic::resume_unwind(panic);
+            }
+        }
+        match self.writer.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+}
+
+/// Writes messages until all the consumers have finished, then closes the file and
+/// gives it its final name.
+///
+/// `groups` are the groups to record the statistics on, by source.
+fn run_writer(
+    mut file: OutputFile,
+    mut writers: HashMap<SourceKey, Box<dyn Writer>>,
+    groups: HashMap<SourceKey, String>,
+    receiver: Receiver<DecodedMessage>,
+    stats: JobStats,
+) -> Result<PathBuf, WriteError> {
+    let journal = journal_path(file.partial_path());
+    update_journal(&journal, &writers);
+    loop {
+        match receiver.recv_timeout(FLUSH_CHECK_INTERVAL) {
+            Ok(message) => {
+                if let Some(writer) = writers.get_mut(&message.key) {
+                    match writer.write(&message.data) {
+                        Ok(()) => {
+                            stats.written(&message.key, message.data.len(), message.timestamp)
+                        }
+                        Err(WriteError::Hdf5(err)) => return Err(WriteError::Hdf5(err)),
+                        Err(err) => {
+                            warn!("dropping message from {}: {err}", message.key.source);
+                            stats.dropped(&message.key);
+                        }
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+        let now = Instant::now();
+        for writer in writers.values_mut() {
+            writer.flush_if_due(now)?;
+        }
+        if file.flush_if_due(now)? {
+            update_journal(&journal, &writers);
+        }
+    }
+    for writer in writers.values_mut() {
+        writer.flush()?;
+    }
+    // The datasets need to be closed before the file.
+    drop(writers);
+    // Adding the statistics means reopening the file in SWMR mode, so only do it
+    // when asked.
+    let path = if groups.is_empty() {
+        file.close()?
+    } else {
+        file.close_with(|file| {
+            for (key, group) in &groups {
+                write_attributes(&file.group(group)?, &stats.get(key))?;
+            }
+            Ok(())
+        })?
+    };
+    // The file is complete, so there is nothing to recover.
+    if let Err(err) = std::fs::remove_file(&journal) {
+        warn!("could not remove journal {}: {err}", journal.display());
+    }
+    Ok(path)
+}
+
+/// Records what has been written so far; only called straight after flushing the
+/// file so the journal never claims more than is in it.
+fn update_journal(path: &Path, writers: &HashMap<SourceKey, Box<dyn Writer>>) {
+    let journal = Journal {
+        datasets: writers
+            .values()
+            .flat_map(|writer| writer.lengths())
+            .collect(),
+    };
+    if let Err(err) = journal.write(path) {
+        warn!("could not update journal {}: {err}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::{F144Builder, TempDir};
+    use crate::json::generate_structure;
+    use crate::nexus::NexusStructure;
+    use crate::output::FileOptions;
+    use crate::process::{Message, StubSource};
+
+    use super::*;
+
+    fn at(secs: u64) -> Option<SystemTime> {
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    #[test]
+    fn stop_time_cannot_change_once_every_topic_has_finished() {
+        let stop_time = StopTime::new(at(10), 2);
+
+        assert!(!stop_time.topic_finished(0, at(10), true));
+        assert!(stop_time.set(at(20).unwrap()));
+        assert!(!stop_time.topic_finished(1, at(20), true));
+        assert!(stop_time.topic_finished(0, at(20), true));
+        assert!(!stop_time.set(at(30).unwrap()));
+        assert_eq!(stop_time.get(), at(20));
+    }
+
+    #[test]
+    fn topics_finished_with_an_old_stop_time_are_ignored() {
+        let stop_time = StopTime::new(at(10), 1);
+
+        stop_time.set(at(20).unwrap());
+
+        assert!(!stop_time.topic_finished(0, at(10), true));
+        assert!(stop_time.topic_finished(0, at(20), true));
+    }
+
+    #[test]
+    fn statistics_are_written_to_the_module_groups() {
+        let dir = TempDir::new("job_stats");
+        let structure: NexusStructure = serde_json::from_str(
+            r#"{"children": [{"name": "log", "type": "group", "children": [
+                {"module": "f144", "config": {"dtype": "double", "source": "temperature", "topic": "motion"}}
+            ]}]}"#,
+        )
+        .unwrap();
+        let mut file = OutputFile::create(dir.join("run.nxs"), FileOptions::default()).unwrap();
+        let modules = generate_structure(&structure, &mut file).unwrap();
+        let messages: Vec<_> = [1, 2, 5]
+            .into_iter()
+            .map(|secs| Message {
+                timestamp: at(secs).unwrap(),
+                data: F144Builder::new("temperature", 1.5)
+                    .timestamp(secs as i64 * 1_000_000_000)
+                    .build(),
+            })
+            .collect();
+        let options = JobOptions {
+            stats_attributes: true,
+            ..JobOptions::default()
+        };
+
+        let job = Job::start(file, &modules, at(0).unwrap(), at(3), options, |_| {
+            Ok::<Box<dyn Source + Send>, String>(Box::new(StubSource::new(messages.clone())))
+        })
+        .unwrap();
+        let path = job.join().unwrap();
+        let file = hdf5::File::open(&path).unwrap();
+        let log = file.group("log").unwrap();
+        let count = |name: &str| log.attr(name).unwrap().read_scalar::<u64>().unwrap();
+        let counts = [
+            count("messages_received"),
+            count("messages_written"),
+            count("messages_dropped"),
+        ];
+
+        assert_eq!(counts, [3, 2, 1]);
+    }
+}
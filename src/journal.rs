@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Records how much of each streamed dataset had been flushed to the file, so an
+/// interrupted file can be trimmed back to a consistent state.
+///
+/// It is kept next to the file while the file is being written and removed once
+/// the file has been closed.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Journal {
+    /// Dataset path to length.
+    pub datasets: BTreeMap<String, usize>,
+}
+
+#[derive(Debug)]
+pub enum JournalError {
+    Io(PathBuf, io::Error),
+    Invalid(PathBuf, serde_json::Error),
+}
+
+impl std::fmt::Display for JournalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JournalError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            JournalError::Invalid(path, err) => {
+                write!(f, "invalid journal {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+/// Where the journal for a file is kept.
+pub fn journal_path(file: &Path) -> PathBuf {
+    let mut path = file.as_os_str().to_owned();
+    path.push(".journal");
+    PathBuf::from(path)
+}
+
+impl Journal {
+    pub fn read(path: &Path) -> Result<Journal, JournalError> {
+        let contents =
+            std::fs::read_to_string(path).map_err(|err| JournalError::Io(path.to_owned(), err))?;
+        serde_json::from_str(&contents).map_err(|err| JournalError::Invalid(path.to_owned(), err))
+    }
+
+    /// Replaces the journal at `path`.
+    ///
+    /// The new journal is written alongside and then renamed over the old one, so a
+    /// crash part way through leaves the old one intact.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        std::fs::write(&temporary, serde_json::to_vec(self)?)?;
+        std::fs::rename(&temporary, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn journal_is_next_to_the_file() {
+        assert_eq!(
+            journal_path(Path::new("/data/run_1.nxs")),
+            PathBuf::from("/data/run_1.nxs.journal")
+        );
+    }
+
+    #[test]
+    fn journal_round_trip() {
+        let dir = TempDir::new("journal");
+        let path = dir.join("run.nxs.journal");
+        let journal = Journal {
+            datasets: BTreeMap::from([
+                ("/entry/events/event_id".to_owned(), 1024),
+                ("/entry/events/event_time_zero".to_owned(), 8),
+            ]),
+        };
+
+        journal.write(&path).unwrap();
+        let read = Journal::read(&path);
+
+        assert_eq!(read.unwrap(), journal);
+    }
+
+    #[test]
+    fn invalid_journal_is_an_error() {
+        let dir = TempDir::new("invalid_journal");
+        let path = dir.join("run.nxs.journal");
+        std::fs::write(&path, "{").unwrap();
+
+        let result = Journal::read(&path);
+
+        assert!(matches!(result, Err(JournalError::Invalid(..))));
+    }
+}
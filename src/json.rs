@@ -0,0 +1,660 @@
+use std::path::Path;
+use std::time::SystemTime;
+
+use hdf5::Result;
+use ndarray::Array;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use crate::nexus::{Attribute, Dataset, Group, NexusNode, NexusStructure, Values};
+use crate::output::{create_in_memory, PARTIAL_SUFFIX};
+use crate::process::SourceKey;
+use crate::template::{resolve_includes, substitute, Variables};
+use crate::values::load_external_values;
+use crate::writers::storage::StorageSettings;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct F144Settings {
+    pub dtype: String,
+    pub source: String,
+    pub topic: String,
+    pub value_units: Option<String>,
+    #[serde(flatten)]
+    pub storage: StorageSettings,
+    #[serde(skip)]
+    pub path: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ev44Settings {
+    pub source: String,
+    pub topic: String,
+    #[serde(flatten)]
+    pub storage: StorageSettings,
+    #[serde(skip)]
+    pub path: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleSettings {
+    F144(F144Settings),
+    Ev44(Ev44Settings),
+}
+
+impl ModuleSettings {
+    pub fn topic(&self) -> &str {
+        match self {
+            ModuleSettings::F144(settings) => &settings.topic,
+            ModuleSettings::Ev44(settings) => &settings.topic,
+        }
+    }
+
+    /// A copy of the settings for writing into the group at `path`.
+    fn with_path(&self, path: String) -> ModuleSettings {
+        let mut settings = self.clone();
+        match &mut settings {
+            ModuleSettings::F144(settings) => settings.path = path,
+            ModuleSettings::Ev44(settings) => settings.path = path,
+        }
+        settings
+    }
+
+    /// The group the module writes into.
+    pub fn path(&self) -> &str {
+        match self {
+            ModuleSettings::F144(settings) => &settings.path,
+            ModuleSettings::Ev44(settings) => &settings.path,
+        }
+    }
+
+    /// The schema and source of the messages this module writes.
+    pub fn source_key(&self) -> SourceKey {
+        match self {
+            ModuleSettings::F144(settings) => SourceKey::new("f144", &settings.source),
+            ModuleSettings::Ev44(settings) => SourceKey::new("ev44", &settings.source),
+        }
+    }
+}
+
+fn write_attributes(location: &hdf5::Location, attributes: &[Attribute]) -> Result<()> {
+    for a in attributes {
+        let avalue: hdf5::types::VarLenUnicode = a.values.parse().map_err(|e| format!("{e}"))?;
+        let attr = location
+            .new_attr::<hdf5::types::VarLenUnicode>()
+            .create(a.name.as_str())?;
+        attr.write_scalar(&avalue)?;
+    }
+    Ok(())
+}
+
+fn write_string_dataset(parent: &hdf5::Group, name: &str, value: &str) -> Result<hdf5::Dataset> {
+    let value: hdf5::types::VarLenUnicode = value.parse().map_err(|e| format!("{e}"))?;
+    let ds = parent
+        .new_dataset::<hdf5::types::VarLenUnicode>()
+        .create(name)?;
+    ds.write_scalar(&value)?;
+    Ok(ds)
+}
+
+/// Extracts the shapes and values for a (potentially) nested array.
+///
+/// The array must be symmetric, meaning the shape is consistent all the way down.
+/// E.g. [[1, 2], [3]] is not symmetric.
+pub(crate) fn extract_array_details(v: &Value) -> Result<(Vec<usize>, Vec<Number>), &'static str> {
+    fn recursion(
+        v: &Value,
+        shape: &mut Vec<usize>,
+        values: &mut Vec<Number>,
+        level: usize,
+    ) -> std::result::Result<(), &'static str> {
+        if v.is_array() {
+            let va = v.as_array().unwrap();
+            if level == shape.len() {
+                shape.push(va.len());
+            } else if shape[level] != va.len() {
+                return Err("array is not symmetric");
+            }
+            for x in va {
+                recursion(x, shape, values, level + 1)?;
+            }
+        } else {
+            let Some(value) = v.as_number() else {
+                return Err("array contains something which is not a number");
+            };
+            values.push(value.clone());
+        }
+        Ok(())
+    }
+    let mut shape = Vec::new();
+    let mut values = Vec::new();
+    if let Err(err) = recursion(v, &mut shape, &mut values, 0) {
+        Err(err)
+    } else {
+        Ok((shape, values))
+    }
+}
+
+/// Writes numeric values to the dataset (including arrays upto 3 dimensions).
+///
+/// Note: writes f32 and i32 values as f64 and i64 because I am lazy.
+fn write_numeric_dataset<T: hdf5::H5Type + Clone>(
+    parent: &hdf5::Group,
+    name: &str,
+    shape: &[usize],
+    values: &[T],
+) -> Result<hdf5::Dataset> {
+    if shape.is_empty() {
+        let ds = parent.new_dataset::<T>().create(name)?;
+        ds.write_scalar(&values[0])?;
+        return Ok(ds);
+    }
+    let data = Array::from_vec(values.to_vec());
+    let data = data
+        .into_shape_with_order(shape.to_vec())
+        .map_err(|e| e.to_string())?;
+    parent.new_dataset_builder().with_data(&data).create(name)
+}
+
+fn write_dataset(parent: &hdf5::Group, dataset: &Dataset) -> Result<()> {
+    let created = match &dataset.values {
+        Values::String(value) => write_string_dataset(parent, &dataset.name, value),
+        Values::Double { shape, values } => {
+            write_numeric_dataset(parent, &dataset.name, shape, values)
+        }
+        Values::Int { shape, values } => {
+            write_numeric_dataset(parent, &dataset.name, shape, values)
+        }
+    }?;
+    write_attributes(&created, &dataset.attributes)
+}
+
+fn write_group(
+    parent: &hdf5::Group,
+    group: &Group,
+    modules: &mut Vec<ModuleSettings>,
+) -> Result<()> {
+    let created = parent.create_group(&group.name)?;
+    write_attributes(&created, &group.attributes)?;
+    write_children(&created, &group.children, modules)
+}
+
+fn write_children(
+    parent: &hdf5::Group,
+    children: &[NexusNode],
+    modules: &mut Vec<ModuleSettings>,
+) -> Result<()> {
+    for child in children {
+        match child {
+            NexusNode::Group(child) => write_group(parent, child, modules)?,
+            NexusNode::Dataset(dataset) => write_dataset(parent, dataset)?,
+            // The target can be relative to the group, as HDF5 resolves soft links.
+            NexusNode::Link(link) => parent.link_soft(&link.source, &link.name)?,
+            NexusNode::Module(module) => {
+                if let Some(settings) = &module.settings {
+                    modules.push(settings.with_path(parent.name()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Creates the static parts of the file from a template, first merging in the
+/// fragments it includes from `template_dir`, loading any dataset values kept in
+/// files there and filling in its placeholders from `variables`.
+pub fn generate_file_contents(
+    json_file: std::fs::File,
+    hdf_file: &mut hdf5::File,
+    template_dir: &Path,
+    variables: &Variables,
+) -> Result<Vec<ModuleSettings>> {
+    let v: Value = serde_json::from_reader(&json_file).map_err(|e| e.to_string())?;
+    let v = resolve_includes(&v, template_dir).map_err(|e| e.to_string())?;
+    let v = load_external_values(&v, template_dir).map_err(|e| e.to_string())?;
+    let v = substitute(&v, variables).map_err(|e| e.to_string())?;
+    let structure = serde_json::from_value(v).map_err(|e| e.to_string())?;
+    generate_structure(&structure, hdf_file)
+}
+
+/// The NeXus format version the files follow.
+pub const NEXUS_VERSION: &str = "4.4.3";
+
+/// The root attributes every file gets, unless the template sets them itself.
+pub const FILE_ATTRIBUTES: &[&str] = &[
+    "file_name",
+    "file_time",
+    "creator",
+    "HDF5_Version",
+    "NeXus_version",
+];
+
+/// The root attributes which identify the file and what wrote it.
+fn file_attributes(
+    file_name: &str,
+    file_time: SystemTime,
+    hdf5_version: (u8, u8, u8),
+) -> Vec<Attribute> {
+    let (major, minor, release) = hdf5_version;
+    let values = [
+        file_name.to_owned(),
+        format_time(file_time),
+        format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")),
+        format!("{major}.{minor}.{release}"),
+        NEXUS_VERSION.to_owned(),
+    ];
+    FILE_ATTRIBUTES
+        .iter()
+        .zip(values)
+        .map(|(name, values)| Attribute {
+            name: name.to_string(),
+            dtype: None,
+            values,
+        })
+        .collect()
+}
+
+/// Adds the defaults which aren't overridden.
+fn with_defaults(defaults: Vec<Attribute>, attributes: &[Attribute]) -> Vec<Attribute> {
+    let mut merged: Vec<_> = defaults
+        .into_iter()
+        .filter(|default| attributes.iter().all(|a| a.name != default.name))
+        .collect();
+    merged.extend(attributes.iter().cloned());
+    merged
+}
+
+/// Formats the time as UTC in ISO 8601, e.g. "2024-03-01T12:00:00Z".
+pub(crate) fn format_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let (days, secs) = (secs / 86400, secs % 86400);
+    // Converts days since the epoch to a date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Creates the static parts of the file from a parsed nexus_structure, e.g. from a
+/// run start message, and returns the streaming modules found.
+pub fn generate_structure(
+    structure: &NexusStructure,
+    hdf_file: &mut hdf5::File,
+) -> Result<Vec<ModuleSettings>> {
+    let mut modules = Vec::new();
+
+    // The top level is usually a single NXentry, but can have several entries and
+    // its own attributes and datasets.
+    // Jobs write to a partial file which is renamed once complete.
+    let file_name = hdf_file.filename();
+    let file_name = file_name.strip_suffix(PARTIAL_SUFFIX).unwrap_or(&file_name);
+    let defaults = file_attributes(file_name, SystemTime::now(), hdf5::library_version());
+    write_attributes(hdf_file, &with_defaults(defaults, &structure.attributes))?;
+    write_children(hdf_file, &structure.children, &mut modules)?;
+    Ok(modules)
+}
+
+/// Finds the streaming modules in a nexus_structure without writing a file.
+///
+/// The structure is generated into an in-memory file, so the modules found are
+/// exactly those a job would write.
+pub fn find_modules(structure: &NexusStructure) -> Result<Vec<ModuleSettings>> {
+    generate_structure(structure, &mut create_in_memory()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+
+    #[test]
+    fn file_attributes_identify_the_writer() {
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_709_294_400);
+
+        let attributes = file_attributes("/data/run.nxs", time, (1, 14, 2));
+
+        let value = |name: &str| {
+            attributes
+                .iter()
+                .find(|a| a.name == name)
+                .map(|a| a.values.clone())
+        };
+        assert_eq!(value("file_name").as_deref(), Some("/data/run.nxs"));
+        assert_eq!(value("file_time").as_deref(), Some("2024-03-01T12:00:00Z"));
+        assert_eq!(value("HDF5_Version").as_deref(), Some("1.14.2"));
+        assert_eq!(value("NeXus_version").as_deref(), Some(NEXUS_VERSION));
+        assert!(value("creator").unwrap().starts_with("filewriter "));
+    }
+
+    #[test]
+    fn template_attributes_override_the_defaults() {
+        let attribute = |name: &str, values: &str| Attribute {
+            name: name.to_owned(),
+            dtype: None,
+            values: values.to_owned(),
+        };
+        let defaults = vec![
+            attribute("creator", "filewriter"),
+            attribute("file_name", "a.nxs"),
+        ];
+
+        let merged = with_defaults(defaults, &[attribute("creator", "ours")]);
+
+        assert_eq!(
+            merged,
+            vec![
+                attribute("file_name", "a.nxs"),
+                attribute("creator", "ours")
+            ]
+        );
+    }
+
+    fn generate(structure: Value) -> hdf5::File {
+        let structure = serde_json::from_value(structure).unwrap();
+        let mut file = create_in_memory().unwrap();
+        generate_structure(&structure, &mut file).unwrap();
+        file
+    }
+
+    #[test]
+    fn dataset_attributes_are_written() {
+        let file = generate(serde_json::json!({"children": [{
+            "name": "entry",
+            "type": "group",
+            "children": [{
+                "module": "dataset",
+                "config": {"name": "duration", "dtype": "double", "values": 1.5},
+                "attributes": [{"name": "units", "dtype": "string", "values": "s"}]
+            }]
+        }]}));
+
+        let units = file
+            .dataset("entry/duration")
+            .unwrap()
+            .attr("units")
+            .unwrap()
+            .read_scalar::<hdf5::types::VarLenUnicode>()
+            .unwrap();
+
+        assert_eq!(units.as_str(), "s");
+    }
+
+    #[test]
+    fn links_are_created() {
+        let file = generate(serde_json::json!({"children": [{
+            "name": "entry",
+            "type": "group",
+            "children": [
+                {"module": "dataset", "config": {"name": "title", "dtype": "string", "values": "x"}},
+                {"name": "sample", "type": "group", "children": [
+                    {"module": "dataset", "config": {"name": "name", "dtype": "string", "values": "y"}}
+                ]},
+                {"module": "link", "config": {"name": "absolute", "source": "/entry/title"}},
+                {"module": "link", "config": {"name": "relative", "source": "sample/name"}}
+            ]
+        }]}));
+
+        let read = |path: &str| {
+            let value = file
+                .dataset(path)
+                .unwrap()
+                .read_scalar::<hdf5::types::VarLenUnicode>();
+            value.unwrap().to_string()
+        };
+
+        assert_eq!(read("entry/absolute"), "x");
+        assert_eq!(read("entry/relative"), "y");
+    }
+
+    #[test]
+    fn format_times_before_and_after_leap_days() {
+        let time = |secs| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+
+        assert_eq!(format_time(time(0)), "1970-01-01T00:00:00Z");
+        assert_eq!(format_time(time(951_782_399)), "2000-02-28T23:59:59Z");
+        assert_eq!(format_time(time(951_868_800)), "2000-03-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_extract_simple_int_array() {
+        let data = r#"
+        {
+            "a": [1, 2, 3, 4, 5]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        assert_eq!(shape, vec![5]);
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_extract_simple_float_array() {
+        let data = r#"
+        {
+            "a": [1.1, 2.2, 3.3, 4.4, 5.5]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<f64> = values.iter().map(|v| v.as_f64().unwrap()).collect();
+
+        assert_eq!(shape, vec![5]);
+        assert_eq!(values, vec![1.1, 2.2, 3.3, 4.4, 5.5]);
+    }
+
+    #[test]
+    fn test_extract_two_level_array_1() {
+        let data = r#"
+        {
+            "a": [[1, 2, 3], [4, 5, 6]]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        assert_eq!(shape, vec![2, 3]);
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_extract_two_level_array_2() {
+        let data = r#"
+        {
+            "a": [[1, 2, 3], [4, 5, 6], [7, 8, 9]]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        assert_eq!(shape, vec![3, 3]);
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_extract_three_level_array_1() {
+        let data = r#"
+        {
+            "a": [
+                     [
+                         [1, 2, 3]
+                     ], 
+                     [
+                         [4, 5, 6]
+                     ]
+                 ]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        assert_eq!(shape, vec![2, 1, 3]);
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_extract_three_level_array_2() {
+        let data = r#"
+        {
+            "a": [
+                     [
+                         [1, 2, 3], [7, 8, 9]
+                     ], 
+                     [
+                         [4, 5, 6], [7, 8, 9]
+                     ]
+                 ]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        assert_eq!(shape, vec![2, 2, 3]);
+        assert_eq!(values, vec![1, 2, 3, 7, 8, 9, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_error_if_not_symmetric_1() {
+        let data = r#"
+        {
+            "a": [[1, 2, 3], [5, 6]]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        assert!(extract_array_details(a).is_err());
+    }
+
+    #[test]
+    fn test_error_if_not_symmetric_2() {
+        let data = r#"
+        {
+            "a": [[1, 2], [5, 6, 7]]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        assert!(extract_array_details(a).is_err());
+    }
+
+    #[test]
+    fn test_error_if_not_symmetric_3() {
+        let data = r#"
+        {
+            "a": [
+                     [
+                         [1, 2, 3], [7, 8]
+                     ], 
+                     [
+                         [4, 5, 6], [7, 8, 9]
+                     ]
+                 ]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        assert!(extract_array_details(a).is_err());
+    }
+
+    #[test]
+    fn test_ndarray_does_what_is_expected_1d() {
+        let data = r#"
+        {
+            "a": [1, 2, 3, 4, 5]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        let data = Array::from_vec(values);
+        let data = data.into_shape_with_order(shape).unwrap();
+
+        assert_eq!(data, array![1, 2, 3, 4, 5].into_dyn());
+    }
+
+    #[test]
+    fn test_ndarray_does_what_is_expected_2d() {
+        let data = r#"
+        {
+            "a": [[1, 2, 3], [3, 4, 5]]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        let data = Array::from_vec(values);
+        let data = data.into_shape_with_order(shape).unwrap();
+
+        assert_eq!(data, array![[1, 2, 3], [3, 4, 5]].into_dyn());
+    }
+
+    #[test]
+    fn test_ndarray_does_what_is_expected_3d() {
+        let data = r#"
+        {
+            "a": [
+                     [
+                         [1, 2, 3], [7, 8, 9]
+                     ], 
+                     [
+                         [4, 5, 6], [7, 8, 9]
+                     ]
+                 ]
+        }"#;
+
+        let j: Value = serde_json::from_str::<Value>(data).unwrap();
+        let a = &j["a"];
+
+        let (shape, values) = extract_array_details(a).unwrap();
+        let values: Vec<i64> = values.iter().map(|v| v.as_i64().unwrap()).collect();
+
+        let data = Array::from_vec(values);
+        let data = data.into_shape_with_order(shape).unwrap();
+
+        assert_eq!(
+            data,
+            array![[[1, 2, 3], [7, 8, 9]], [[4, 5, 6], [7, 8, 9]]].into_dyn()
+        );
+    }
+}
@@ -0,0 +1,365 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::{Duration, SystemTime};
+
+use log::{debug, warn};
+use rdkafka::consumer::{BaseConsumer, Consumer};
+use rdkafka::error::{KafkaError, KafkaResult};
+use rdkafka::{ClientConfig, Message as _, Offset, TopicPartitionList};
+
+use crate::capture::{CaptureError, CaptureRecord, CaptureWriter};
+use crate::process::{identify, Message, Source, Time};
+
+/// How long to wait for the broker when looking up topics and offsets.
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long `poll` waits for a message before giving up.
+const POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Converts to ms since the epoch, as used for Kafka timestamps.
+pub fn to_millis(time: SystemTime) -> i64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+pub fn from_millis(millis: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+}
+
+/// Reads a topic from Kafka, starting at the first message at or after the start time.
+pub struct KafkaSource {
+    consumer: BaseConsumer,
+    /// Whether each partition has been read up to its end.
+    at_end: HashMap<i32, bool>,
+}
+
+impl KafkaSource {
+    pub fn new(broker: &str, topic: &str, start_time: SystemTime) -> KafkaResult<KafkaSource> {
+        // The end of partition events tell us when we've caught up.
+        let consumer = create_consumer(broker, true)?;
+        let at_end = assign_from(&consumer, topic, start_time)?
+            .into_iter()
+            .map(|p| (p, false))
+            .collect();
+        Ok(KafkaSource { consumer, at_end })
+    }
+}
+
+fn create_consumer(broker: &str, partition_eof: bool) -> KafkaResult<BaseConsumer> {
+    ClientConfig::new()
+        .set("bootstrap.servers", broker)
+        .set("group.id", format!("filewriter-{}", std::process::id()))
+        .set("enable.auto.commit", "false")
+        .set("enable.partition.eof", partition_eof.to_string())
+        .create()
+}
+
+/// Assigns all the partitions of the topic to the consumer, starting at the first
+/// message at or after the start time, and returns the partitions.
+fn assign_from(
+    consumer: &BaseConsumer,
+    topic: &str,
+    start_time: SystemTime,
+) -> KafkaResult<Vec<i32>> {
+    let metadata = consumer.fetch_metadata(Some(topic), METADATA_TIMEOUT)?;
+    let Some(topic_metadata) = metadata.topics().first() else {
+        return Err(KafkaError::MetadataFetch(
+            rdkafka::types::RDKafkaErrorCode::UnknownTopic,
+        ));
+    };
+    if let Some(err) = topic_metadata.error() {
+        return Err(KafkaError::MetadataFetch(err.into()));
+    }
+
+    let mut partitions = TopicPartitionList::new();
+    for partition in topic_metadata.partitions() {
+        partitions.add_partition_offset(
+            topic,
+            partition.id(),
+            Offset::Offset(to_millis(start_time)),
+        )?;
+    }
+    // Partitions without any messages after the start time come back as the end
+    // offset, so we only get new messages for those.
+    let offsets = consumer.offsets_for_times(partitions, METADATA_TIMEOUT)?;
+    consumer.assign(&offsets)?;
+    Ok(topic_metadata.partitions().iter().map(|p| p.id()).collect())
+}
+
+impl Source for KafkaSource {
+    fn poll(&mut self) -> Option<Message> {
+        match self.consumer.poll(POLL_TIMEOUT)? {
+            Ok(message) => {
+                self.at_end.insert(message.partition(), false);
+                let timestamp = message.timestamp().to_millis()?;
+                Some(Message {
+                    timestamp: from_millis(timestamp.max(0) as u64),
+                    data: message.payload()?.to_vec(),
+                })
+            }
+            Err(KafkaError::PartitionEOF(partition)) => {
+                self.at_end.insert(partition, true);
+                None
+            }
+            Err(err) => {
+                warn!("error consuming from Kafka: {err}");
+                None
+            }
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.at_end.values().all(|at_end| *at_end)
+    }
+
+    fn blocks(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordError {
+    Kafka(KafkaError),
+    Capture(CaptureError),
+}
+
+impl std::fmt::Display for RecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordError::Kafka(err) => write!(f, "Kafka error: {err}"),
+            RecordError::Capture(err) => write!(f, "could not write capture: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for RecordError {}
+
+impl From<KafkaError> for RecordError {
+    fn from(err: KafkaError) -> Self {
+        RecordError::Kafka(err)
+    }
+}
+
+impl From<CaptureError> for RecordError {
+    fn from(err: CaptureError) -> Self {
+        RecordError::Capture(err)
+    }
+}
+
+/// Turns a message read from Kafka into a record for a capture.
+///
+/// Everything is recorded, so the schema and source are only filled in as far as
+/// they can be worked out.
+pub fn capture_record(
+    topic: &str,
+    partition: i32,
+    offset: i64,
+    timestamp: SystemTime,
+    data: Vec<u8>,
+) -> CaptureRecord {
+    let (schema, source) = match identify(&data) {
+        Some(key) => (key.schema, key.source),
+        None => {
+            let schema = data
+                .get(4..8)
+                .and_then(|id| std::str::from_utf8(id).ok())
+                .unwrap_or("????");
+            (schema.to_owned(), String::new())
+        }
+    };
+    CaptureRecord {
+        timestamp,
+        topic: topic.to_owned(),
+        partition,
+        offset,
+        schema,
+        source,
+        data,
+    }
+}
+
+/// How far through its messages a partition being recorded is.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct PartitionProgress {
+    /// Seen a message after the stop time.
+    past_stop: bool,
+    /// Read everything that's currently on the partition.
+    at_end: bool,
+}
+
+impl PartitionProgress {
+    /// Whether nothing more will arrive before the stop time. If we've read
+    /// everything but the stop time hasn't been reached, more could still arrive.
+    fn is_finished(&self, stop_time: SystemTime, now: SystemTime) -> bool {
+        self.past_stop || (self.at_end && now > stop_time)
+    }
+}
+
+/// Records the messages on one topic between the start and stop times.
+struct TopicRecorder {
+    topic: String,
+    consumer: BaseConsumer,
+    partitions: HashMap<i32, PartitionProgress>,
+}
+
+impl TopicRecorder {
+    fn new(broker: &str, topic: &str, start_time: SystemTime) -> KafkaResult<TopicRecorder> {
+        // The end of partition events tell us when we've caught up.
+        let consumer = create_consumer(broker, true)?;
+        let partitions = assign_from(&consumer, topic, start_time)?
+            .into_iter()
+            .map(|p| (p, PartitionProgress::default()))
+            .collect();
+        Ok(TopicRecorder {
+            topic: topic.to_owned(),
+            consumer,
+            partitions,
+        })
+    }
+
+    /// Gets the next message to record, if there is one waiting.
+    fn poll(&mut self, stop_time: SystemTime) -> Option<CaptureRecord> {
+        match self.consumer.poll(POLL_TIMEOUT)? {
+            Ok(message) => {
+                let progress = self.partitions.entry(message.partition()).or_default();
+                progress.at_end = false;
+                let timestamp = from_millis(message.timestamp().to_millis()?.max(0) as u64);
+                if timestamp > stop_time {
+                    progress.past_stop = true;
+                    return None;
+                }
+                Some(capture_record(
+                    &self.topic,
+                    message.partition(),
+                    message.offset(),
+                    timestamp,
+                    message.payload().unwrap_or_default().to_vec(),
+                ))
+            }
+            Err(KafkaError::PartitionEOF(partition)) => {
+                self.partitions.entry(partition).or_default().at_end = true;
+                None
+            }
+            Err(err) => {
+                warn!("error consuming from {}: {err}", self.topic);
+                None
+            }
+        }
+    }
+
+    /// Whether nothing more will arrive before the stop time.
+    fn is_finished(&self, stop_time: SystemTime, now: SystemTime) -> bool {
+        self.partitions
+            .values()
+            .all(|p| p.is_finished(stop_time, now))
+    }
+}
+
+/// Records every message on the topics between the start and stop times into the
+/// capture, waiting for the stop time to pass if it hasn't yet.
+///
+/// Returns the number of messages recorded.
+pub fn record<W: Write, T: Time>(
+    broker: &str,
+    topics: &[String],
+    start_time: SystemTime,
+    stop_time: SystemTime,
+    capture: &mut CaptureWriter<W>,
+    wall_clock: &T,
+) -> Result<usize, RecordError> {
+    let mut recorders = topics
+        .iter()
+        .map(|topic| TopicRecorder::new(broker, topic, start_time))
+        .collect::<KafkaResult<Vec<_>>>()?;
+    let mut count = 0;
+    while !recorders.is_empty() {
+        for recorder in &mut recorders {
+            if let Some(record) = recorder.poll(stop_time) {
+                capture.write(&record)?;
+                count += 1;
+            }
+        }
+        let now = wall_clock.now();
+        recorders.retain(|recorder| {
+            let finished = recorder.is_finished(stop_time, now);
+            if finished {
+                debug!("finished recording {}", recorder.topic);
+            }
+            !finished
+        });
+    }
+    capture.flush().map_err(CaptureError::from)?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::F144Builder;
+
+    use super::*;
+
+    #[test]
+    fn capture_record_identifies_known_messages() {
+        let data = F144Builder::new("temperature", 1.5).build();
+
+        let record = capture_record("motion", 2, 40, SystemTime::UNIX_EPOCH, data.clone());
+
+        assert_eq!(record.schema, "f144");
+        assert_eq!(record.source, "temperature");
+        assert_eq!(record.partition, 2);
+        assert_eq!(record.offset, 40);
+        assert_eq!(record.data, data);
+    }
+
+    #[test]
+    fn capture_record_keeps_unknown_messages() {
+        let record = capture_record(
+            "motion",
+            0,
+            0,
+            SystemTime::UNIX_EPOCH,
+            b"1234abcdxyz".to_vec(),
+        );
+
+        assert_eq!(record.schema, "abcd");
+        assert_eq!(record.source, "");
+    }
+
+    #[test]
+    fn partition_finished_once_past_stop_time() {
+        let stop_time = from_millis(2000);
+        let progress = PartitionProgress {
+            past_stop: true,
+            at_end: false,
+        };
+
+        assert!(progress.is_finished(stop_time, from_millis(1000)));
+    }
+
+    #[test]
+    fn partition_at_end_waits_for_stop_time() {
+        let stop_time = from_millis(2000);
+        let progress = PartitionProgress {
+            past_stop: false,
+            at_end: true,
+        };
+
+        assert!(!progress.is_finished(stop_time, from_millis(1000)));
+        assert!(progress.is_finished(stop_time, from_millis(3000)));
+    }
+
+    #[test]
+    fn partition_still_reading_is_not_finished() {
+        let progress = PartitionProgress::default();
+
+        assert!(!progress.is_finished(from_millis(2000), from_millis(3000)));
+    }
+
+    #[test]
+    fn capture_record_keeps_messages_too_short_for_a_schema() {
+        let record = capture_record("motion", 0, 0, SystemTime::UNIX_EPOCH, vec![1, 2]);
+
+        assert_eq!(record.schema, "????");
+        assert_eq!(record.data, [1, 2]);
+    }
+}
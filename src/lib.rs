@@ -0,0 +1,56 @@
+#[allow(
+    dead_code,
+    unused_imports,
+    non_camel_case_types,
+    mismatched_lifetime_syntaxes,
+    clippy::all
+)]
+pub mod ad00_area_detector_array_generated;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod answ_action_response_generated;
+#[allow(
+    dead_code,
+    unused_imports,
+    non_camel_case_types,
+    mismatched_lifetime_syntaxes,
+    clippy::all
+)]
+pub mod da00_dataarray_generated;
+pub mod capture;
+pub mod cli;
+pub mod dump;
+pub mod ev44;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod ev44_events_generated;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod f144_logdata_generated;
+pub mod export;
+pub mod fixtures;
+pub mod job;
+pub mod journal;
+pub mod json;
+pub mod kafka;
+pub mod manager;
+pub mod nexus;
+pub mod output;
+pub mod paths;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod pl72_run_start_generated;
+pub mod process;
+pub mod recover;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod run_stop_6s4t_generated;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod se00_data_generated;
+pub mod service;
+pub mod signals;
+pub mod stats;
+pub mod status;
+pub mod template;
+pub mod validate;
+pub mod values;
+pub mod writers;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod wrdn_finished_writing_generated;
+#[allow(dead_code, unused_imports, mismatched_lifetime_syntaxes, clippy::all)]
+pub mod x5f2_status_generated;
@@ -1,57 +1,314 @@
-use ev44_events_generated::{root_as_event_44_message, Event44Message, Event44MessageArgs};
-use hdf5::File;
-
-mod ev44;
-mod ev44_events_generated;
-mod process;
-
-fn create_flatbuffer() -> Vec<u8> {
-    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
-    let source = builder.create_string("SomeSource");
-
-    let reference_times = vec![123456_i64];
-    let reference_time_offset = builder.create_vector(&reference_times);
-    let reference_index_offset = builder.create_vector(&vec![0_i32]);
-
-    let tof_offset = builder.create_vector(&vec![100, 200, 300, 4000, 50000]);
-    let pixels_offset = builder.create_vector(&vec![1, 2, 3]);
-
-    let ev44 = Event44Message::create(
-        &mut builder,
-        &Event44MessageArgs {
-            source_name: Some(source),
-            message_id: 123,
-            reference_time: Some(reference_time_offset),
-            reference_time_index: Some(reference_index_offset),
-            time_of_flight: Some(tof_offset),
-            pixel_id: Some(pixels_offset),
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime};
+
+use clap::Parser;
+use log::{error, info, warn, LevelFilter};
+use serde_json::Value;
+
+use filewriter::capture::{time_range, CaptureWriter};
+use filewriter::cli::{
+    Cli, Command, DryRunArgs, ExportArgs, RecordArgs, RecoverArgs, ReplayArgs, ValidateArgs,
+    WriteArgs,
+};
+use filewriter::dump::dry_run;
+use filewriter::export::{export, ExportOptions};
+use filewriter::job::{Job, JobOptions};
+use filewriter::json::find_modules;
+use filewriter::kafka::{self, from_millis};
+use filewriter::nexus::NexusStructure;
+use filewriter::output::FileOptions;
+use filewriter::paths::prepare_output;
+use filewriter::process::{CaptureSource, Source, WallClock};
+use filewriter::recover::recover;
+use filewriter::service::{kafka_connector, start_job, Service};
+use filewriter::signals;
+use filewriter::template::{resolve_includes, substitute, Variables};
+use filewriter::validate::validate;
+use filewriter::values::load_external_values;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Serve(args) => {
+            let args = match args.with_config_file() {
+                Ok(args) => args,
+                Err(err) => {
+                    init_logging(cli.log_level);
+                    error!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            init_logging(cli.log_level.or(args.log_level));
+            install_signal_handlers();
+            match Service::new(args.resolve()) {
+                Ok(mut service) => {
+                    service.run();
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    error!("could not connect to Kafka: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Write(args) => {
+            init_logging(cli.log_level);
+            install_signal_handlers();
+            report(write(&args))
+        }
+        Command::Replay(args) => {
+            init_logging(cli.log_level);
+            install_signal_handlers();
+            report(replay(&args))
+        }
+        Command::Record(args) => {
+            init_logging(cli.log_level);
+            report(record(&args))
+        }
+        Command::Validate(args) => {
+            init_logging(cli.log_level);
+            report(validate_template(&args))
+        }
+        Command::DryRun(args) => {
+            init_logging(cli.log_level);
+            report(dry_run_template(&args))
+        }
+        Command::Export(args) => {
+            init_logging(cli.log_level);
+            report(export_template(&args))
+        }
+        Command::Recover(args) => {
+            init_logging(cli.log_level);
+            report(recover_file(&args))
+        }
+    }
+}
+
+fn install_signal_handlers() {
+    if let Err(err) = signals::install() {
+        warn!("could not install signal handlers, so stopping may leave files unfinished: {err}");
+    }
+}
+
+/// Waits for the job to finish, or for the process to be told to stop in which case
+/// the job is cut short and its file closed.
+fn wait_for(job: Job) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    while !job.is_finished() {
+        if signals::stop_requested() {
+            info!("stopping early");
+            job.interrupt();
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+    Ok(job.join()?)
+}
+
+fn report(result: Result<(), Box<dyn std::error::Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            error!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn init_logging(level: Option<LevelFilter>) {
+    env_logger::Builder::new()
+        .filter_level(level.unwrap_or(LevelFilter::Info))
+        .init();
+}
+
+/// Reads the template, merging in the fragments it includes and the dataset
+/// values it keeps in files from its directory.
+fn read_template(path: &Path) -> Result<Value, Box<dyn std::error::Error>> {
+    let template = std::fs::read_to_string(path)?;
+    let structure = serde_json::from_str(&template)?;
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let structure = resolve_includes(&structure, dir)?;
+    Ok(load_external_values(&structure, dir)?)
+}
+
+/// Reads the template, fills in its placeholders and parses it.
+fn read_filled_template(
+    path: &Path,
+    variables: &[(String, String)],
+) -> Result<NexusStructure, Box<dyn std::error::Error>> {
+    let structure = read_template(path)?;
+    let variables: Variables = variables.iter().cloned().collect();
+    let structure = substitute(&structure, &variables)?;
+    Ok(serde_json::from_value(structure)?)
+}
+
+/// Writes a single file, without waiting for a command.
+fn write(args: &WriteArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let structure = read_filled_template(&args.template, &args.variables)?;
+    let start_time = args.start.map_or_else(SystemTime::now, from_millis);
+    let stop_time = args.stop.map(from_millis);
+    prepare_output(&args.output, args.overwrite, 0)?;
+
+    info!("writing {}", args.output.display());
+    let job = start_job(
+        &args.output,
+        &structure,
+        start_time,
+        stop_time,
+        FileOptions {
+            swmr: !args.no_swmr,
+            ..FileOptions::default()
+        },
+        JobOptions {
+            stats_attributes: args.stats_attributes,
+            ..JobOptions::default()
         },
+        kafka_connector(&args.broker, start_time),
+    )?;
+    let path = wait_for(job)?;
+    info!("finished writing {}", path.display());
+    Ok(())
+}
+
+/// Writes a file from a capture instead of Kafka.
+fn replay(args: &ReplayArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let Some((first, last)) = time_range(&args.capture)? else {
+        return Err(format!("{} has no messages", args.capture.display()).into());
+    };
+    let structure = read_filled_template(&args.template, &args.variables)?;
+    let start_time = args.start.map_or(first, from_millis);
+    let stop_time = args.stop.map_or(last, from_millis);
+    prepare_output(&args.output, args.overwrite, 0)?;
+
+    info!(
+        "writing {} from {}",
+        args.output.display(),
+        args.capture.display()
+    );
+    let job = start_job(
+        &args.output,
+        &structure,
+        start_time,
+        Some(stop_time),
+        FileOptions {
+            swmr: !args.no_swmr,
+            ..FileOptions::default()
+        },
+        JobOptions {
+            stats_attributes: args.stats_attributes,
+            ..JobOptions::default()
+        },
+        |topic| {
+            CaptureSource::open(&args.capture, topic)
+                .map(|source| Box::new(source) as Box<dyn Source + Send>)
+        },
+    )?;
+    let path = wait_for(job)?;
+    info!("finished writing {}", path.display());
+    Ok(())
+}
+
+/// Records the messages the template would write into a capture.
+fn record(args: &RecordArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let structure: NexusStructure = serde_json::from_value(read_template(&args.template)?)?;
+    let mut topics: Vec<String> = find_modules(&structure)?
+        .iter()
+        .map(|module| module.topic().to_owned())
+        .collect();
+    topics.sort();
+    topics.dedup();
+
+    info!(
+        "recording {} into {}",
+        topics.join(", "),
+        args.output.display()
     );
-    builder.finish(ev44, Some("ev44"));
-    builder.finished_data().to_vec()
-}
-
-fn main() {
-    println!("Hello, world!");
-    let buf = create_flatbuffer();
-    let root = root_as_event_44_message(&buf).unwrap();
-    let time_of_flight = root.time_of_flight().unwrap();
-
-    let hfile = File::create("example.hf").unwrap();
-    let ds = hfile
-        .new_dataset::<i32>()
-        .chunk((1, time_of_flight.len()))
-        .shape((1.., time_of_flight.len()))
-        .create("chunky")
-        .unwrap();
-
-    if let Ok(tofs) = bytemuck::try_cast_slice::<u8, i32>(&time_of_flight.bytes()) {
-        println!("using bytemuck");
-        ds.write_slice(&tofs, (0, ..)).unwrap();
+    let file = BufWriter::new(File::create(&args.output)?);
+    let mut capture = CaptureWriter::new(file, &topics)?;
+    let count = kafka::record(
+        &args.broker,
+        &topics,
+        from_millis(args.start),
+        from_millis(args.stop),
+        &mut capture,
+        &WallClock,
+    )?;
+    info!("recorded {count} messages");
+    Ok(())
+}
+
+/// Prints every problem with the template.
+fn validate_template(args: &ValidateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // Anything which can't be parsed stops the check straight away.
+    let structure: NexusStructure = serde_json::from_value(read_template(&args.template)?)?;
+    let problems = validate(&structure);
+    let mut errors = 0;
+    for problem in &problems {
+        if problem.kind.is_warning() {
+            println!("warning: {problem}");
+        } else {
+            println!("{problem}");
+            errors += 1;
+        }
+    }
+    if errors == 0 {
+        println!("{} is valid", args.template.display());
+        Ok(())
+    } else {
+        Err(format!("{errors} problems found").into())
+    }
+}
+
+/// Prints the tree the template would produce.
+fn dry_run_template(args: &DryRunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let structure = read_filled_template(&args.template, &args.variables)?;
+    let tree = dry_run(&structure)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&tree)?);
     } else {
-        // Resort to coping the vector.
-        let tofs: Vec<i32> = time_of_flight.iter().collect();
-        ds.write_slice(&tofs, (0, ..)).unwrap();
+        print!("{tree}");
     }
+    Ok(())
+}
 
+/// Writes a template, and any `.npy` files it needs, which would produce the file.
+fn export_template(args: &ExportArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let file = hdf5::File::open(&args.file)?;
+    let options = ExportOptions {
+        max_inline_values: args.max_inline_values,
+    };
+    let exported = export(&file, &options)?;
+    let dir = args.output.parent().unwrap_or(Path::new("."));
+    for (name, contents) in &exported.files {
+        std::fs::write(dir.join(name), contents)?;
+    }
+    std::fs::write(
+        &args.output,
+        serde_json::to_string_pretty(&exported.structure)?,
+    )?;
+    info!(
+        "exported {} to {} with {} value files",
+        args.file.display(),
+        args.output.display(),
+        exported.files.len()
+    );
+    Ok(())
+}
+
+/// Trims an interrupted file back to what its journal says was flushed.
+fn recover_file(args: &RecoverArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let recovered = recover(&args.file)?;
+    for trim in &recovered.trims {
+        info!(
+            "trimmed {} from {} to {} values",
+            trim.path, trim.length, trim.journaled
+        );
+    }
+    info!("recovered {}", recovered.path.display());
+    Ok(())
 }
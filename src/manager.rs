@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde_json::{json, Value};
+
+use crate::kafka::to_millis;
+
+/// A job which is being written, along with what it was started with.
+pub struct RunningJob<J> {
+    pub job_id: String,
+    pub path: PathBuf,
+    pub start_time: SystemTime,
+    pub stop_time: Option<SystemTime>,
+    pub job: J,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum StartRefused {
+    /// Already writing the maximum number of jobs.
+    AtCapacity(usize),
+    DuplicateJobId(String),
+    /// Another job is already writing the file.
+    FileInUse(PathBuf),
+}
+
+impl std::fmt::Display for StartRefused {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartRefused::AtCapacity(max_jobs) => {
+                write!(f, "already writing the maximum of {max_jobs} jobs")
+            }
+            StartRefused::DuplicateJobId(job_id) => write!(f, "job {job_id} is already running"),
+            StartRefused::FileInUse(path) => {
+                write!(f, "{} is already being written", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StartRefused {}
+
+/// Keeps track of the jobs being written at the same time, by job id.
+///
+/// It doesn't know how jobs are run, so `J` can be anything; [`JobManager::take_finished`]
+/// is told how to check whether one is done.
+pub struct JobManager<J> {
+    max_jobs: usize,
+    jobs: Vec<RunningJob<J>>,
+}
+
+impl<J> JobManager<J> {
+    pub fn new(max_jobs: usize) -> JobManager<J> {
+        JobManager {
+            max_jobs,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Checks whether a job writing `path` could be started now.
+    pub fn can_start(&self, job_id: &str, path: &Path) -> Result<(), StartRefused> {
+        if self.get(job_id).is_some() {
+            return Err(StartRefused::DuplicateJobId(job_id.to_owned()));
+        }
+        if self.jobs.iter().any(|job| job.path == path) {
+            return Err(StartRefused::FileInUse(path.to_owned()));
+        }
+        if self.jobs.len() >= self.max_jobs {
+            return Err(StartRefused::AtCapacity(self.max_jobs));
+        }
+        Ok(())
+    }
+
+    /// Adds a job which has been started, if it is allowed.
+    pub fn add(&mut self, job: RunningJob<J>) -> Result<(), StartRefused> {
+        self.can_start(&job.job_id, &job.path)?;
+        self.jobs.push(job);
+        Ok(())
+    }
+
+    pub fn get(&self, job_id: &str) -> Option<&RunningJob<J>> {
+        self.jobs.iter().find(|job| job.job_id == job_id)
+    }
+
+    pub fn get_mut(&mut self, job_id: &str) -> Option<&mut RunningJob<J>> {
+        self.jobs.iter_mut().find(|job| job.job_id == job_id)
+    }
+
+    pub fn jobs(&self) -> &[RunningJob<J>] {
+        &self.jobs
+    }
+
+    /// Removes the jobs which have finished, in the order they were started.
+    pub fn take_finished(&mut self, is_finished: impl Fn(&J) -> bool) -> Vec<RunningJob<J>> {
+        let (finished, running) = std::mem::take(&mut self.jobs)
+            .into_iter()
+            .partition(|job| is_finished(&job.job));
+        self.jobs = running;
+        finished
+    }
+
+    /// The status of all the jobs, for the status messages, with `sources` giving
+    /// the statistics for each job's sources.
+    pub fn status_json(&self, sources: impl Fn(&J) -> Value) -> Value {
+        let jobs: Vec<_> = self
+            .jobs
+            .iter()
+            .map(|job| {
+                json!({
+                    "job_id": job.job_id,
+                    "file_being_written": job.path,
+                    "start_time": to_millis(job.start_time),
+                    "stop_time": job.stop_time.map(to_millis),
+                    "sources": sources(&job.job),
+                })
+            })
+            .collect();
+        json!({
+            "state": if jobs.is_empty() { "idle" } else { "writing" },
+            "max_jobs": self.max_jobs,
+            "jobs": jobs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn running(job_id: &str, file: &str) -> RunningJob<bool> {
+        RunningJob {
+            job_id: job_id.to_owned(),
+            path: PathBuf::from(file),
+            start_time: SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            stop_time: None,
+            job: false,
+        }
+    }
+
+    #[test]
+    fn jobs_can_run_up_to_the_maximum() {
+        let mut manager = JobManager::new(2);
+
+        manager.add(running("job_1", "a.nxs")).unwrap();
+        manager.add(running("job_2", "b.nxs")).unwrap();
+
+        assert_eq!(
+            manager.add(running("job_3", "c.nxs")),
+            Err(StartRefused::AtCapacity(2))
+        );
+        assert_eq!(manager.jobs().len(), 2);
+    }
+
+    #[test]
+    fn job_ids_must_be_unique() {
+        let mut manager = JobManager::new(2);
+        manager.add(running("job_1", "a.nxs")).unwrap();
+
+        assert_eq!(
+            manager.can_start("job_1", Path::new("b.nxs")),
+            Err(StartRefused::DuplicateJobId("job_1".to_owned()))
+        );
+    }
+
+    #[test]
+    fn two_jobs_cannot_write_the_same_file() {
+        let mut manager = JobManager::new(2);
+        manager.add(running("job_1", "a.nxs")).unwrap();
+
+        assert_eq!(
+            manager.can_start("job_2", Path::new("a.nxs")),
+            Err(StartRefused::FileInUse(PathBuf::from("a.nxs")))
+        );
+    }
+
+    #[test]
+    fn jobs_are_found_by_id() {
+        let mut manager = JobManager::new(2);
+        manager.add(running("job_1", "a.nxs")).unwrap();
+        manager.add(running("job_2", "b.nxs")).unwrap();
+
+        assert_eq!(
+            manager.get("job_2").map(|job| &job.path),
+            Some(&PathBuf::from("b.nxs"))
+        );
+        assert!(manager.get("job_3").is_none());
+    }
+
+    #[test]
+    fn finished_jobs_make_room_for_more() {
+        let mut manager = JobManager::new(2);
+        manager.add(running("job_1", "a.nxs")).unwrap();
+        manager.add(running("job_2", "b.nxs")).unwrap();
+        manager.get_mut("job_1").unwrap().job = true;
+
+        let finished = manager.take_finished(|done| *done);
+
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].job_id, "job_1");
+        assert!(manager.add(running("job_3", "c.nxs")).is_ok());
+    }
+
+    #[test]
+    fn status_lists_every_job() {
+        let mut manager = JobManager::new(2);
+        assert_eq!(manager.status_json(|_| json!([]))["state"], "idle");
+
+        manager.add(running("job_1", "a.nxs")).unwrap();
+        manager.add(running("job_2", "b.nxs")).unwrap();
+
+        let status = manager.status_json(|done| json!([{ "done": done }]));
+        assert_eq!(status["state"], "writing");
+        assert_eq!(status["max_jobs"], 2);
+        assert_eq!(status["jobs"][1]["job_id"], "job_2");
+        assert_eq!(status["jobs"][1]["file_being_written"], "b.nxs");
+        assert_eq!(status["jobs"][1]["start_time"], 1000);
+        assert_eq!(status["jobs"][1]["sources"][0]["done"], false);
+    }
+}
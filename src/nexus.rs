@@ -0,0 +1,402 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Number, Value};
+
+use crate::json::{extract_array_details, Ev44Settings, F144Settings, ModuleSettings};
+
+/// Static datasets are limited to this many dimensions.
+pub const MAX_DIMENSIONS: usize = 3;
+
+/// A parsed nexus_structure, as sent in a run start message.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct NexusStructure {
+    /// Attributes of the file itself.
+    #[serde(default, deserialize_with = "attributes")]
+    pub attributes: Vec<Attribute>,
+    #[serde(default)]
+    pub children: Vec<NexusNode>,
+}
+
+/// Something in the nexus_structure.
+///
+/// Groups are marked by `"type": "group"` and everything else by its `module`,
+/// where "dataset" and "link" are static and the rest are streamed.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(try_from = "RawNode")]
+pub enum NexusNode {
+    Group(Group),
+    Dataset(Dataset),
+    Link(Link),
+    Module(Module),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+    pub children: Vec<NexusNode>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    pub name: String,
+    pub dtype: Dtype,
+    pub values: Values,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Link {
+    pub name: String,
+    /// The path of the target, either absolute or relative to the link's group.
+    pub source: String,
+}
+
+/// A streaming module; only the modules which can be written have settings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Module {
+    pub module: String,
+    pub settings: Option<ModuleSettings>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct Attribute {
+    pub name: String,
+    /// Only strings are supported, so the dtype is ignored.
+    #[serde(default)]
+    pub dtype: Option<String>,
+    pub values: String,
+}
+
+/// The dtypes a static dataset can have.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dtype {
+    String,
+    Double,
+    Float,
+    Int32,
+    Int64,
+}
+
+impl std::str::FromStr for Dtype {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Dtype::deserialize(&Value::from(s))
+    }
+}
+
+/// The values of a static dataset; arrays are flattened with their shape alongside.
+///
+/// Note: float and int32 datasets are held (and written) as f64 and i64.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Values {
+    String(String),
+    Double { shape: Vec<usize>, values: Vec<f64> },
+    Int { shape: Vec<usize>, values: Vec<i64> },
+}
+
+impl Values {
+    /// Checks the values can be written with the dtype, e.g. that int datasets only
+    /// have integers and arrays aren't ragged.
+    pub fn new(dtype: Dtype, v: &Value) -> Result<Values, &'static str> {
+        if dtype == Dtype::String {
+            return match v {
+                Value::String(s) => Ok(Values::String(s.clone())),
+                _ => Err("string datasets need a string value"),
+            };
+        }
+        let (shape, numbers) = if v.is_array() {
+            let (shape, numbers) = extract_array_details(v)?;
+            if shape.len() > MAX_DIMENSIONS {
+                return Err("static data is limited to three dimensions");
+            }
+            (shape, numbers)
+        } else {
+            (
+                Vec::new(),
+                vec![v.as_number().ok_or("not a number")?.clone()],
+            )
+        };
+        if dtype == Dtype::Int32 || dtype == Dtype::Int64 {
+            let values = numbers
+                .iter()
+                .map(Number::as_i64)
+                .collect::<Option<_>>()
+                .ok_or("integer datasets need integer values")?;
+            Ok(Values::Int { shape, values })
+        } else {
+            let values = numbers
+                .iter()
+                .map(Number::as_f64)
+                .collect::<Option<_>>()
+                .ok_or("not a number")?;
+            Ok(Values::Double { shape, values })
+        }
+    }
+}
+
+/// Any node, before working out which kind it is.
+#[derive(Deserialize)]
+struct RawNode {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    name: Option<String>,
+    module: Option<String>,
+    config: Option<Value>,
+    #[serde(default, deserialize_with = "attributes")]
+    attributes: Vec<Attribute>,
+    #[serde(default)]
+    children: Vec<NexusNode>,
+}
+
+#[derive(Deserialize)]
+struct DatasetConfig {
+    name: String,
+    dtype: Dtype,
+    values: Value,
+}
+
+impl TryFrom<RawNode> for NexusNode {
+    type Error = String;
+
+    fn try_from(raw: RawNode) -> Result<Self, Self::Error> {
+        match (raw.kind.as_deref(), raw.module.as_deref()) {
+            (Some("group"), _) => Ok(NexusNode::Group(Group {
+                name: raw.name.ok_or("group has no name")?,
+                attributes: raw.attributes,
+                children: raw.children,
+            })),
+            (Some(kind), _) => Err(format!("unknown node type {kind}")),
+            (None, Some(module)) => {
+                let config = raw
+                    .config
+                    .ok_or_else(|| format!("{module} module has no config"))?;
+                if !raw.children.is_empty() {
+                    return Err(format!("{module} module can't have children"));
+                }
+                let invalid = |err: serde_json::Error| format!("invalid {module} config: {err}");
+                match module {
+                    "dataset" => {
+                        let config = DatasetConfig::deserialize(config).map_err(invalid)?;
+                        let values = Values::new(config.dtype, &config.values).map_err(|err| {
+                            format!("invalid values for dataset {}: {err}", config.name)
+                        })?;
+                        Ok(NexusNode::Dataset(Dataset {
+                            name: config.name,
+                            dtype: config.dtype,
+                            values,
+                            attributes: raw.attributes,
+                        }))
+                    }
+                    "link" => Ok(NexusNode::Link(Link::deserialize(config).map_err(invalid)?)),
+                    _ => {
+                        let settings = match module {
+                            "f144" => Some(ModuleSettings::F144(
+                                F144Settings::deserialize(config).map_err(invalid)?,
+                            )),
+                            "ev44" => Some(ModuleSettings::Ev44(
+                                Ev44Settings::deserialize(config).map_err(invalid)?,
+                            )),
+                            _ => None,
+                        };
+                        Ok(NexusNode::Module(Module {
+                            module: module.to_owned(),
+                            settings,
+                        }))
+                    }
+                }
+            }
+            (None, None) => Err("node has neither a type nor a module".to_owned()),
+        }
+    }
+}
+
+/// Attributes are either a list or a map from name to value.
+fn attributes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Attribute>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Attributes {
+        List(Vec<Attribute>),
+        Map(serde_json::Map<String, Value>),
+    }
+
+    match Attributes::deserialize(deserializer)? {
+        Attributes::List(attributes) => Ok(attributes),
+        Attributes::Map(map) => map
+            .into_iter()
+            .map(|(name, values)| match values {
+                Value::String(values) => Ok(Attribute {
+                    name,
+                    dtype: None,
+                    values,
+                }),
+                _ => Err(serde::de::Error::custom(format!(
+                    "attribute {name} must be a string"
+                ))),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn parse(v: Value) -> Result<NexusNode, serde_json::Error> {
+        NexusNode::deserialize(v)
+    }
+
+    #[test]
+    fn parse_example_template() {
+        let v: Value = serde_json::from_str(include_str!("../nxs.json")).unwrap();
+
+        let structure = NexusStructure::deserialize(v).unwrap();
+
+        let [NexusNode::Group(entry)] = structure.children.as_slice() else {
+            panic!("expected a single group");
+        };
+        assert_eq!(entry.attributes[0].values, "NXentry");
+    }
+
+    #[test]
+    fn top_level_can_have_several_entries_attributes_and_datasets() {
+        let structure = NexusStructure::deserialize(json!({
+            "attributes": [{"name": "creator", "values": "filewriter"}],
+            "children": [
+                {"name": "entry", "type": "group"},
+                {"name": "entry_1", "type": "group"},
+                {"module": "dataset", "config": {"name": "file_name", "dtype": "string", "values": "a.nxs"}}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(structure.attributes[0].name, "creator");
+        assert_eq!(structure.children.len(), 3);
+        assert!(matches!(&structure.children[2], NexusNode::Dataset(_)));
+    }
+
+    #[test]
+    fn parse_group_with_children() {
+        let node = parse(json!({
+            "name": "sample",
+            "type": "group",
+            "attributes": [{"name": "NX_class", "dtype": "string", "values": "NXsample"}],
+            "children": [
+                {"module": "dataset", "config": {"name": "m", "dtype": "int32", "values": [[1, 2], [3, 4]]}},
+                {"module": "link", "config": {"name": "l", "source": "/entry/m"}},
+                {"module": "f144", "config": {"source": "s", "topic": "t", "dtype": "double"}},
+                {"module": "tdct", "config": {"source": "c", "topic": "t"}}
+            ]
+        }))
+        .unwrap();
+
+        let NexusNode::Group(group) = node else {
+            panic!("expected a group");
+        };
+        assert_eq!(group.name, "sample");
+        assert_eq!(
+            group.children[0],
+            NexusNode::Dataset(Dataset {
+                name: "m".to_owned(),
+                dtype: Dtype::Int32,
+                values: Values::Int {
+                    shape: vec![2, 2],
+                    values: vec![1, 2, 3, 4]
+                },
+                attributes: Vec::new(),
+            })
+        );
+        assert_eq!(
+            group.children[1],
+            NexusNode::Link(Link {
+                name: "l".to_owned(),
+                source: "/entry/m".to_owned()
+            })
+        );
+        assert!(matches!(
+            &group.children[2],
+            NexusNode::Module(Module {
+                settings: Some(ModuleSettings::F144(_)),
+                ..
+            })
+        ));
+        assert!(matches!(
+            &group.children[3],
+            NexusNode::Module(Module { settings: None, .. })
+        ));
+    }
+
+    #[test]
+    fn attributes_can_be_a_map() {
+        let node =
+            parse(json!({"name": "entry", "type": "group", "attributes": {"NX_class": "NXentry"}}))
+                .unwrap();
+
+        let NexusNode::Group(group) = node else {
+            panic!("expected a group");
+        };
+        assert_eq!(group.attributes[0].name, "NX_class");
+        assert_eq!(group.attributes[0].values, "NXentry");
+    }
+
+    #[test]
+    fn attribute_map_values_must_be_strings() {
+        let result =
+            parse(json!({"name": "entry", "type": "group", "attributes": {"NX_class": 1}}));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_symmetric_array_is_an_error() {
+        let result = parse(json!({
+            "module": "dataset",
+            "config": {"name": "x", "dtype": "double", "values": [[1, 2], [3]]}
+        }));
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("array is not symmetric"));
+    }
+
+    #[test]
+    fn values_must_match_the_dtype() {
+        let result = parse(
+            json!({"module": "dataset", "config": {"name": "x", "dtype": "int64", "values": [1.5]}}),
+        );
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("integer datasets need integer values"));
+    }
+
+    #[test]
+    fn invalid_module_config_is_an_error() {
+        let result = parse(json!({"module": "f144", "config": {"source": "s"}}));
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("invalid f144 config"));
+    }
+
+    #[test]
+    fn unknown_dtype_is_an_error() {
+        let result = parse(
+            json!({"module": "dataset", "config": {"name": "x", "dtype": "uint8", "values": 1}}),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn node_must_be_a_group_or_module() {
+        assert!(parse(json!({"name": "x"})).is_err());
+        assert!(parse(json!({"name": "x", "type": "dataset"})).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use hdf5::File;
+
+/// Settings for how the output file is written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileOptions {
+    /// Allows the file to be read while it is being written (single-writer/multiple-reader).
+    ///
+    /// This needs a filesystem which respects POSIX write ordering, so it should be
+    /// turned off for things like NFS.
+    pub swmr: bool,
+    /// How often the file is flushed so readers see the latest data.
+    pub flush_interval: Duration,
+}
+
+impl Default for FileOptions {
+    fn default() -> Self {
+        FileOptions {
+            swmr: true,
+            flush_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Added to the name of a file while it is being written.
+pub const PARTIAL_SUFFIX: &str = ".partial";
+
+/// Where a file is written before it is complete.
+pub fn partial_path(path: &Path) -> PathBuf {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(PARTIAL_SUFFIX);
+    PathBuf::from(partial)
+}
+
+/// The name a partial file gets once it is complete, or None if it isn't one.
+pub fn complete_path(partial: &Path) -> Option<PathBuf> {
+    let name = partial.file_name()?.to_str()?;
+    let name = name.strip_suffix(PARTIAL_SUFFIX)?;
+    (!name.is_empty()).then(|| partial.with_file_name(name))
+}
+
+/// Finds the partial files under `dir`, e.g. those left behind by a crash.
+pub fn find_partial_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut found = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            found.extend(find_partial_files(&path)?);
+        } else if complete_path(&path).is_some() {
+            found.push(path);
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// The HDF5 file a job writes to.
+///
+/// The file is created, the static structure generated and the writers created
+/// before calling `start_streaming`. In SWMR mode no new objects can be added after
+/// that point, only existing datasets extended.
+///
+/// The file is written with [`PARTIAL_SUFFIX`] on the end of its name and only
+/// renamed once it has been closed, so anything watching for new files doesn't pick
+/// it up half-written.
+pub struct OutputFile {
+    file: File,
+    /// Where the file ends up once it is complete.
+    path: PathBuf,
+    partial: PathBuf,
+    options: FileOptions,
+    streaming: bool,
+    last_flush: Instant,
+}
+
+impl OutputFile {
+    pub fn create<P: AsRef<Path>>(path: P, options: FileOptions) -> hdf5::Result<OutputFile> {
+        let path = path.as_ref();
+        let partial = partial_path(path);
+        let file = if options.swmr {
+            // SWMR needs the file format introduced in 1.10.
+            File::with_options()
+                .with_fapl(|p| p.libver_v110())
+                .create(&partial)?
+        } else {
+            File::create(&partial)?
+        };
+        Ok(OutputFile {
+            file,
+            path: path.to_owned(),
+            partial,
+            options,
+            streaming: false,
+            last_flush: Instant::now(),
+        })
+    }
+
+    /// Switches the file into SWMR mode, if enabled, ready for the writers to start
+    /// appending data.
+    pub fn start_streaming(&mut self) -> hdf5::Result<()> {
+        if self.streaming {
+            return Ok(());
+        }
+        if self.options.swmr {
+            self.file.flush()?;
+            let result =
+                hdf5::sync::sync(|| unsafe { hdf5_sys::h5f::H5Fstart_swmr_write(self.file.id()) });
+            if result < 0 {
+                return Err("could not switch file to SWMR mode".into());
+            }
+        }
+        self.streaming = true;
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+
+    /// Where the file is being written.
+    pub fn partial_path(&self) -> &Path {
+        &self.partial
+    }
+
+    pub fn is_swmr(&self) -> bool {
+        self.streaming && self.options.swmr
+    }
+
+    /// Flushes the file if the flush interval has passed since the last flush,
+    /// returning whether it did.
+    ///
+    /// The writers need to have flushed their buffers first for the data to be
+    /// visible.
+    pub fn flush_if_due(&mut self, now: Instant) -> hdf5::Result<bool> {
+        if now.saturating_duration_since(self.last_flush) < self.options.flush_interval {
+            return Ok(false);
+        }
+        self.flush(now)?;
+        Ok(true)
+    }
+
+    pub fn flush(&mut self, now: Instant) -> hdf5::Result<()> {
+        self.file.flush()?;
+        self.last_flush = now;
+        Ok(())
+    }
+
+    /// Closes the file and renames it to its final name, which is returned.
+    pub fn close(self) -> hdf5::Result<PathBuf> {
+        self.file.flush()?;
+        self.file.close()?;
+        rename_complete(&self.partial, self.path)
+    }
+
+    /// As for `close`, but first calls `finish` to add anything which couldn't be
+    /// added while streaming.
+    ///
+    /// In SWMR mode the file is closed and reopened for this, as no attributes can
+    /// be created in that mode.
+    pub fn close_with<F>(self, finish: F) -> hdf5::Result<PathBuf>
+    where
+        F: FnOnce(&File) -> hdf5::Result<()>,
+    {
+        self.file.flush()?;
+        if self.is_swmr() {
+            self.file.close()?;
+            let file = File::open_rw(&self.partial)?;
+            finish(&file)?;
+            file.close()?;
+        } else {
+            finish(&self.file)?;
+            self.file.close()?;
+        }
+        rename_complete(&self.partial, self.path)
+    }
+}
+
+/// Gives a closed file its final name.
+fn rename_complete(partial: &Path, path: PathBuf) -> hdf5::Result<PathBuf> {
+    std::fs::rename(partial, &path).map_err(|err| {
+        format!(
+            "could not rename {} to {}: {err}",
+            partial.display(),
+            path.display()
+        )
+    })?;
+    Ok(path)
+}
+
+/// Creates a file which only exists in memory, e.g. to see what a template produces
+/// without writing anything.
+pub fn create_in_memory() -> hdf5::Result<File> {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    // HDF5 won't open two files with the same name at once.
+    let name = format!(
+        "filewriter-in-memory-{}-{}",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::Relaxed)
+    );
+    File::with_options()
+        .with_fapl(|p| p.core_filebacked(false))
+        .create(std::env::temp_dir().join(name))
+}
+
+impl Deref for OutputFile {
+    type Target = File;
+
+    fn deref(&self) -> &File {
+        &self.file
+    }
+}
+
+impl DerefMut for OutputFile {
+    fn deref_mut(&mut self) -> &mut File {
+        &mut self.file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn partial_file_is_next_to_the_final_one() {
+        let partial = partial_path(Path::new("/data/run_1.nxs"));
+
+        assert_eq!(partial, PathBuf::from("/data/run_1.nxs.partial"));
+        assert_eq!(
+            complete_path(&partial),
+            Some(PathBuf::from("/data/run_1.nxs"))
+        );
+    }
+
+    #[test]
+    fn other_files_are_not_partial() {
+        assert_eq!(complete_path(Path::new("/data/run_1.nxs")), None);
+        assert_eq!(complete_path(Path::new("/data/.partial")), None);
+    }
+
+    #[test]
+    fn partial_files_are_found_in_subdirectories() {
+        let dir = TempDir::new("partial_files");
+        std::fs::create_dir_all(dir.join("cycle_1")).unwrap();
+        for name in ["a.nxs", "b.nxs.partial", "cycle_1/c.nxs.partial"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let found = find_partial_files(&dir);
+
+        assert_eq!(
+            found.unwrap(),
+            vec![dir.join("b.nxs.partial"), dir.join("cycle_1/c.nxs.partial")]
+        );
+    }
+}
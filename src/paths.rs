@@ -0,0 +1,227 @@
+use std::path::{Component, Path, PathBuf};
+
+use crate::output::partial_path;
+use crate::template::{substitute_string, UnresolvedPlaceholders, Variables};
+
+#[derive(Debug)]
+pub enum PathError {
+    Placeholders(UnresolvedPlaceholders),
+    /// The file name is empty or would put the file outside the output directory,
+    /// e.g. via "..".
+    OutsideOutputDir(String),
+    Exists(PathBuf),
+    CreateDir(PathBuf, std::io::Error),
+    NotEnoughSpace {
+        dir: PathBuf,
+        free: u64,
+        needed: u64,
+    },
+}
+
+impl std::fmt::Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::Placeholders(err) => write!(f, "invalid file name: {err}"),
+            PathError::OutsideOutputDir(name) => {
+                write!(f, "file name {name:?} must be inside the output directory")
+            }
+            PathError::Exists(path) => write!(f, "{} already exists", path.display()),
+            PathError::CreateDir(dir, err) => {
+                write!(f, "could not create {}: {err}", dir.display())
+            }
+            PathError::NotEnoughSpace { dir, free, needed } => write!(
+                f,
+                "only {free} bytes free in {}, need at least {needed}",
+                dir.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+impl From<UnresolvedPlaceholders> for PathError {
+    fn from(err: UnresolvedPlaceholders) -> Self {
+        PathError::Placeholders(err)
+    }
+}
+
+/// Works out where a job's file goes by filling in the `$NAME$` placeholders in
+/// the file name template, which may include subdirectories.
+pub fn output_path(
+    output_dir: &Path,
+    template: &str,
+    variables: &Variables,
+) -> Result<PathBuf, PathError> {
+    let name = substitute_string(template, variables)?;
+    let relative = Path::new(&name);
+    let inside = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if !inside || relative.file_name().is_none() {
+        return Err(PathError::OutsideOutputDir(name));
+    }
+    Ok(output_dir.join(relative))
+}
+
+/// Gets ready to write a file: refuses to replace an existing one unless allowed,
+/// creates any missing directories and checks there is at least `min_free_bytes`
+/// of space.
+///
+/// A partial file with the same name counts as existing, as it could be another
+/// job's or one waiting to be recovered.
+pub fn prepare_output(path: &Path, overwrite: bool, min_free_bytes: u64) -> Result<(), PathError> {
+    if !overwrite {
+        for existing in [path.to_owned(), partial_path(path)] {
+            if existing.exists() {
+                return Err(PathError::Exists(existing));
+            }
+        }
+    }
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    std::fs::create_dir_all(dir).map_err(|err| PathError::CreateDir(dir.to_owned(), err))?;
+    if min_free_bytes > 0 {
+        if let Some(free) = free_space(dir) {
+            if free < min_free_bytes {
+                return Err(PathError::NotEnoughSpace {
+                    dir: dir.to_owned(),
+                    free,
+                    needed: min_free_bytes,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The space available on the filesystem holding `dir`, if it can be found.
+#[cfg(unix)]
+pub fn free_space(dir: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(dir.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::zeroed();
+    if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    let stat = unsafe { stat.assume_init() };
+    // The fields are narrower than u64 on some platforms.
+    #[allow(clippy::useless_conversion)]
+    let free = u64::from(stat.f_bavail).saturating_mul(u64::from(stat.f_frsize));
+    Some(free)
+}
+
+/// Not supported here, so the space isn't checked.
+#[cfg(not(unix))]
+pub fn free_space(_dir: &Path) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::TempDir;
+
+    use super::*;
+
+    fn variables(pairs: &[(&str, &str)]) -> Variables {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn file_name_template_is_filled_in() {
+        let path = output_path(
+            Path::new("/data"),
+            "$PROPOSAL$/$DATE$/run_$RUN_NUMBER$.nxs",
+            &variables(&[
+                ("PROPOSAL", "12345"),
+                ("DATE", "2024-03-01"),
+                ("RUN_NUMBER", "7"),
+            ]),
+        );
+
+        assert_eq!(
+            path.unwrap(),
+            PathBuf::from("/data/12345/2024-03-01/run_7.nxs")
+        );
+    }
+
+    #[test]
+    fn missing_variable_is_an_error() {
+        let result = output_path(
+            Path::new("/data"),
+            "run_$RUN_NUMBER$.nxs",
+            &Variables::new(),
+        );
+
+        assert!(matches!(result, Err(PathError::Placeholders(_))));
+    }
+
+    #[test]
+    fn file_must_stay_in_the_output_directory() {
+        for name in ["../run.nxs", "/etc/run.nxs", "a/../../run.nxs", ""] {
+            let result = output_path(Path::new("/data"), name, &Variables::new());
+
+            assert!(
+                matches!(result, Err(PathError::OutsideOutputDir(_))),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn existing_file_is_not_overwritten_unless_allowed() {
+        let dir = TempDir::new("paths_existing");
+        let path = dir.join("run.nxs");
+        std::fs::write(&path, "").unwrap();
+
+        let refused = prepare_output(&path, false, 0);
+        let allowed = prepare_output(&path, true, 0);
+
+        assert!(matches!(refused, Err(PathError::Exists(_))));
+        assert!(allowed.is_ok());
+    }
+
+    #[test]
+    fn leftover_partial_file_counts_as_existing() {
+        let dir = TempDir::new("paths_partial");
+        let path = dir.join("run.nxs");
+        std::fs::write(partial_path(&path), "").unwrap();
+
+        let result = prepare_output(&path, false, 0);
+
+        assert!(
+            matches!(result, Err(PathError::Exists(existing)) if existing == partial_path(&path))
+        );
+    }
+
+    #[test]
+    fn missing_directories_are_created() {
+        let dir = TempDir::new("paths_mkdir");
+        let path = dir.join("12345/run.nxs");
+
+        let result = prepare_output(&path, false, 0);
+        let created = dir.join("12345").is_dir();
+
+        assert!(result.is_ok());
+        assert!(created);
+    }
+
+    #[test]
+    fn not_enough_space_is_an_error() {
+        let dir = TempDir::new("paths_space");
+        let path = dir.join("run.nxs");
+
+        let result = prepare_output(&path, false, u64::MAX);
+
+        if free_space(&dir).is_some() {
+            assert!(matches!(result, Err(PathError::NotEnoughSpace { .. })));
+        }
+    }
+}
@@ -1,71 +1,444 @@
-use std::{cell::RefCell, collections::HashMap, time::SystemTime};
+use std::{
+    collections::HashMap,
+    sync::mpsc::SyncSender,
+    time::{Duration, SystemTime},
+};
+use std::{fs::File, io::BufReader, path::Path};
 
-trait Time {
+use log::warn;
+
+use crate::capture::{CaptureError, CaptureReader};
+use crate::ev44::Ev44;
+use crate::ev44_events_generated::Event44Message;
+use crate::f144_logdata_generated::{root_as_log_data, LogData};
+use crate::stats::JobStats;
+
+pub trait Time {
     fn now(&self) -> SystemTime;
 }
 
-struct StubWallClock {
-    times: Vec<SystemTime>,
-    index: RefCell<usize>,
-}
+pub struct WallClock;
 
-impl Time for StubWallClock {
+impl Time for WallClock {
     fn now(&self) -> SystemTime {
-        let mut index = self.index.borrow_mut();
-        if *index >= self.times.len() {
-            return *self.times.last().unwrap();
+        SystemTime::now()
+    }
+}
+
+/// A raw message as read from a topic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Message {
+    /// When the message was produced, as given by the broker.
+    pub timestamp: SystemTime,
+    pub data: Vec<u8>,
+}
+
+/// Identifies the writer a message belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceKey {
+    pub schema: String,
+    pub source: String,
+}
+
+impl SourceKey {
+    pub fn new(schema: &str, source: &str) -> SourceKey {
+        SourceKey {
+            schema: schema.to_owned(),
+            source: source.to_owned(),
         }
-        let result = self.times.get(*index);
-        *index += 1;
-        *result.unwrap()
     }
 }
 
-struct Streamer {}
+/// A message which has been matched to its source.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedMessage {
+    pub key: SourceKey,
+    pub timestamp: SystemTime,
+    pub data: Vec<u8>,
+}
+
+/// Works out the schema and source of a message.
+///
+/// Returns None if the schema isn't one we can write or the source can't be read.
+pub fn decode(message: Message) -> Option<DecodedMessage> {
+    Some(DecodedMessage {
+        key: identify(&message.data)?,
+        timestamp: message.timestamp,
+        data: message.data,
+    })
+}
+
+/// Works out the schema and source of a flatbuffer, if it's one we can write.
+///
+/// Only the source name is read, so a message which is otherwise invalid can
+/// still be matched to its source; see [`is_valid`].
+pub fn identify(data: &[u8]) -> Option<SourceKey> {
+    let schema = std::str::from_utf8(data.get(4..8)?).ok()?;
+    let field = match schema {
+        "ev44" => Event44Message::VT_SOURCE_NAME,
+        "f144" => LogData::VT_SOURCE_NAME,
+        _ => return None,
+    };
+    Some(SourceKey::new(schema, read_string_field(data, field)?))
+}
+
+/// Whether the whole of a message can be written, e.g. that an ev44's pulses are
+/// in order.
+pub fn is_valid(key: &SourceKey, data: &[u8]) -> bool {
+    match key.schema.as_str() {
+        "ev44" => Ev44::from_bytes(data).is_ok(),
+        "f144" => root_as_log_data(data).is_ok(),
+        _ => false,
+    }
+}
+
+/// Reads a string field of a flatbuffer's root table without verifying anything
+/// else. Every offset is checked against the buffer, so any input is safe.
+fn read_string_field(data: &[u8], field: flatbuffers::VOffsetT) -> Option<&str> {
+    let u16_at = |pos: usize| {
+        let bytes = data.get(pos..pos.checked_add(2)?)?;
+        Some(u16::from_le_bytes(bytes.try_into().ok()?) as usize)
+    };
+    let u32_at = |pos: usize| {
+        let bytes = data.get(pos..pos.checked_add(4)?)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    };
+    let table = u32_at(0)? as usize;
+    // The table starts with a signed offset back to its vtable.
+    let vtable = (table as i64).checked_sub(u32_at(table)? as i32 as i64)?;
+    let vtable = usize::try_from(vtable).ok()?;
+    if field as usize + 2 > u16_at(vtable)? {
+        return None;
+    }
+    let offset = u16_at(vtable + field as usize)?;
+    if offset == 0 {
+        return None;
+    }
+    let position = table.checked_add(offset)?;
+    let string = position.checked_add(u32_at(position)? as usize)?;
+    let start = string.checked_add(4)?;
+    let bytes = data.get(start..start.checked_add(u32_at(string)? as usize)?)?;
+    std::str::from_utf8(bytes).ok()
+}
+
+/// Somewhere messages for a topic come from.
+pub trait Source {
+    /// Gets the next message, if there is one.
+    ///
+    /// Implementations may wait a short while for a message to arrive.
+    fn poll(&mut self) -> Option<Message>;
+
+    /// Whether everything currently on the topic has been read, so an empty poll
+    /// means there is nothing more yet rather than that we haven't caught up.
+    fn at_end(&self) -> bool;
+
+    /// Whether `poll` waits for a message to arrive, so there is no need to back off
+    /// after an empty poll.
+    fn blocks(&self) -> bool {
+        false
+    }
+}
+
+/// How far past the stop time a topic has to get before we assume nothing more will
+/// arrive for the sources which haven't sent anything since, e.g. an f144 which only
+/// publishes on change. Messages are only in order per source, so this allows for
+/// some reordering between sources.
+pub const STOP_LEEWAY: Duration = Duration::from_secs(10);
+
+/// Reads the messages for one topic and passes those for the job's sources on to
+/// the writer thread.
+pub struct Streamer {
+    /// The sources on the topic and whether each has seen a message after the stop time.
+    sources: HashMap<SourceKey, bool>,
+    /// The stop time the sources were last checked against.
+    stop_time: Option<SystemTime>,
+    /// The latest timestamp of any message on the topic, including other sources'.
+    latest: Option<SystemTime>,
+    /// Messages for the sources after the stop time, in case it is moved later.
+    held: Vec<DecodedMessage>,
+    stats: JobStats,
+    /// Whether the last poll of the source got nothing.
+    idle: bool,
+    /// Whether the writer thread has gone.
+    disconnected: bool,
+}
 
 impl Streamer {
-    fn process<T: Time>(
-        &self,
-        source: &mut StubSource,
+    pub fn new<I: IntoIterator<Item = SourceKey>>(sources: I) -> Streamer {
+        Streamer {
+            sources: sources.into_iter().map(|key| (key, false)).collect(),
+            stop_time: None,
+            latest: None,
+            held: Vec::new(),
+            stats: JobStats::default(),
+            idle: false,
+            disconnected: false,
+        }
+    }
+
+    /// Counts the messages received and dropped for each source in `stats`.
+    pub fn with_stats(mut self, stats: JobStats) -> Streamer {
+        self.stats = stats;
+        self
+    }
+
+    /// Notes a change of stop time; if it is later then the sources which were past
+    /// the old one may have more to write, starting with the messages held back.
+    ///
+    /// Returns true if the writer thread has gone.
+    fn update_stop_time(
+        &mut self,
+        stop_time: Option<SystemTime>,
+        start_time: &SystemTime,
+        writers: &SyncSender<DecodedMessage>,
+    ) -> bool {
+        let later = match (stop_time, self.stop_time) {
+            (None, Some(_)) => true,
+            (Some(new), Some(old)) => new > old,
+            _ => false,
+        };
+        self.stop_time = stop_time;
+        if later {
+            self.sources
+                .values_mut()
+                .for_each(|finished| *finished = false);
+            for message in std::mem::take(&mut self.held) {
+                if self.handle(message, start_time, writers) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Whether the topic has got past the stop time, either for every source or by
+    /// enough that the sources which are quiet won't send anything more before it.
+    fn is_past_stop(&self) -> bool {
+        let Some(stop) = self.stop_time else {
+            return false;
+        };
+        let sources_past = !self.sources.is_empty() && self.sources.values().all(|f| *f);
+        sources_past
+            || self
+                .latest
+                .is_some_and(|latest| latest > stop + STOP_LEEWAY)
+    }
+
+    /// Whether there is nothing more to write for the topic, including when the wall
+    /// clock has passed the stop time and everything on the topic has been read.
+    fn is_finished<S: Source + ?Sized, T: Time>(&self, source: &S, wall_clock: &T) -> bool {
+        self.is_past_stop()
+            || self
+                .stop_time
+                .is_some_and(|stop| source.at_end() && wall_clock.now() > stop)
+    }
+
+    /// Whether the source had no message last time, so the caller should wait a
+    /// little before trying again if the source doesn't block.
+    pub fn is_idle(&self) -> bool {
+        self.idle
+    }
+
+    /// Whether the writer thread has gone, so there is nowhere to write to.
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected
+    }
+
+    /// Counts the messages held back after the stop time as dropped, as they won't
+    /// be written now.
+    pub fn close(self) {
+        for message in &self.held {
+            self.stats.dropped(&message.key);
+        }
+    }
+
+    /// Handles the next message from the source, if any.
+    ///
+    /// Sending to the writer thread blocks if it has fallen behind, which stops us
+    /// reading any further ahead.
+    ///
+    /// The stop time can change between calls. If it is already in the past, any
+    /// messages before it are still written as they are read, and the topic is
+    /// finished once every source has gone past it, once any message on the topic is
+    /// more than [`STOP_LEEWAY`] past it, or once the wall clock has passed it and
+    /// everything on the topic has been read. A finished topic isn't read any further,
+    /// but it carries on if the stop time is moved later.
+    ///
+    /// Returns true once there is nothing more to write for this topic.
+    pub fn process<S: Source + ?Sized, T: Time>(
+        &mut self,
+        source: &mut S,
         start_time: &SystemTime,
         stop_time: Option<SystemTime>,
-        //writers: &mut HashMap<&str, Writer>,
+        writers: &SyncSender<DecodedMessage>,
         wall_clock: &T,
     ) -> bool {
-        let Some(message) = source.poll() else {
-            if stop_time.is_some() {
-                return wall_clock.now() > stop_time.unwrap();
+        if self.update_stop_time(stop_time, start_time, writers) {
+            self.disconnected = true;
+            return true;
+        }
+        if self.is_finished(source, wall_clock) {
+            self.idle = true;
+            return true;
+        }
+        let message = source.poll();
+        self.idle = message.is_none();
+        let Some(message) = message else {
+            return self.is_finished(source, wall_clock);
+        };
+        self.latest = self.latest.max(Some(message.timestamp));
+        let Some(message) = decode(message) else {
+            return self.is_past_stop();
+        };
+        if !self.sources.contains_key(&message.key) {
+            return self.is_past_stop();
+        }
+        self.stats.received(&message.key);
+        if self.handle(message, start_time, writers) {
+            self.disconnected = true;
+            return true;
+        }
+        self.is_past_stop()
+    }
+
+    /// Sends a message for one of the sources to the writer thread if it's in the
+    /// job's time window, holding it back if it's after the stop time.
+    ///
+    /// Returns true if the writer thread has gone.
+    fn handle(
+        &mut self,
+        message: DecodedMessage,
+        start_time: &SystemTime,
+        writers: &SyncSender<DecodedMessage>,
+    ) -> bool {
+        if self.stop_time.is_some_and(|stop| message.timestamp > stop) {
+            // Messages are in order per source, so nothing more will arrive for it.
+            if let Some(finished) = self.sources.get_mut(&message.key) {
+                *finished = true;
             }
+            self.held.push(message);
             return false;
-        };
-        false
+        }
+        if message.timestamp < *start_time {
+            self.stats.dropped(&message.key);
+            return false;
+        }
+        if !is_valid(&message.key, &message.data) {
+            warn!("dropping invalid message from {}", message.key.source);
+            self.stats.dropped(&message.key);
+            return false;
+        }
+        writers.send(message).is_err()
     }
 }
 
-struct StubSource {
-    data: Vec<String>,
+pub struct StubSource {
+    data: Vec<Message>,
     index: usize,
+    lagging: bool,
 }
 
 impl StubSource {
-    fn poll(&mut self) -> Option<(String)> {
+    pub fn new(data: Vec<Message>) -> StubSource {
+        StubSource {
+            data,
+            index: 0,
+            lagging: false,
+        }
+    }
+
+    /// Acts as if there are more messages on the topic which haven't been read yet.
+    pub fn lagging(mut self) -> StubSource {
+        self.lagging = true;
+        self
+    }
+}
+
+impl Source for StubSource {
+    fn poll(&mut self) -> Option<Message> {
         let result = self.data.get(self.index);
         if result.is_some() {
             self.index += 1;
         }
         result.cloned()
     }
+
+    fn at_end(&self) -> bool {
+        !self.lagging && self.index >= self.data.len()
+    }
+}
+
+/// Replays the messages for one topic from a capture file.
+pub struct CaptureSource {
+    records: CaptureReader<BufReader<File>>,
+    topic: String,
+    at_end: bool,
+}
+
+impl CaptureSource {
+    pub fn open<P: AsRef<Path>>(path: P, topic: &str) -> Result<CaptureSource, CaptureError> {
+        Ok(CaptureSource {
+            records: CaptureReader::open(path)?,
+            topic: topic.to_owned(),
+            at_end: false,
+        })
+    }
+}
+
+impl Source for CaptureSource {
+    fn poll(&mut self) -> Option<Message> {
+        for record in self.records.by_ref() {
+            match record {
+                Ok(record) if record.topic == self.topic => {
+                    return Some(Message {
+                        timestamp: record.timestamp,
+                        data: record.data,
+                    })
+                }
+                Ok(_) => {}
+                Err(err) => warn!("stopped replaying {}: {err}", self.topic),
+            }
+        }
+        self.at_end = true;
+        None
+    }
+
+    fn at_end(&self) -> bool {
+        self.at_end
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::sync::mpsc::{sync_channel, Receiver};
     use std::time::Duration;
 
+    use crate::fixtures::{Ev44Builder, TempDir};
+
     use super::*;
+
     fn to_system_time(input: u64) -> SystemTime {
         SystemTime::UNIX_EPOCH + Duration::from_secs(input)
     }
 
+    struct StubWallClock {
+        times: Vec<SystemTime>,
+        index: RefCell<usize>,
+    }
+
+    impl Time for StubWallClock {
+        fn now(&self) -> SystemTime {
+            let mut index = self.index.borrow_mut();
+            if *index >= self.times.len() {
+                return *self.times.last().unwrap();
+            }
+            let result = self.times.get(*index);
+            *index += 1;
+            *result.unwrap()
+        }
+    }
+
     fn generate_wall_clock(times: Vec<u64>) -> StubWallClock {
         let times = times.iter().map(|x| to_system_time(*x)).collect();
         StubWallClock {
@@ -74,20 +447,31 @@ mod tests {
         }
     }
 
+    fn channel() -> (SyncSender<DecodedMessage>, Receiver<DecodedMessage>) {
+        sync_channel(16)
+    }
+
+    fn ev44_message(source: &str, timestamp: u64) -> Message {
+        Message {
+            timestamp: to_system_time(timestamp),
+            data: Ev44Builder::new(source)
+                .pulse(timestamp as i64 * 1_000_000_000, &[100, 200], &[1, 2])
+                .build(),
+        }
+    }
+
     #[test]
     fn finished_when_wall_clock_exceeds_stop_time() {
         let wall_clock = generate_wall_clock(vec![2100]);
         let start_time = to_system_time(0);
         let stop_time = Some(to_system_time(2000));
-        let streamer = Streamer {};
-        let mut source = StubSource {
-            data: Vec::new(),
-            index: 0,
-        };
+        let mut streamer = Streamer::new([]);
+        let mut source = StubSource::new(Vec::new());
+        let (writers, _receiver) = channel();
 
-        let finished = streamer.process(&mut source, &start_time, stop_time, &wall_clock);
+        let finished = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
 
-        assert_eq!(finished, true);
+        assert!(finished);
     }
 
     #[test]
@@ -95,15 +479,43 @@ mod tests {
         let wall_clock = generate_wall_clock(vec![1900]);
         let start_time = to_system_time(0);
         let stop_time = Some(to_system_time(2000));
-        let streamer = Streamer {};
-        let mut source = StubSource {
-            data: Vec::new(),
-            index: 0,
-        };
+        let mut streamer = Streamer::new([]);
+        let mut source = StubSource::new(Vec::new());
+        let (writers, _receiver) = channel();
+
+        let finished = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+
+        assert!(!finished);
+    }
 
-        let finished = streamer.process(&mut source, &start_time, stop_time, &wall_clock);
+    #[test]
+    fn not_finished_when_wall_clock_exceeds_stop_time_but_not_caught_up() {
+        let wall_clock = generate_wall_clock(vec![2100]);
+        let start_time = to_system_time(0);
+        let stop_time = Some(to_system_time(2000));
+        let mut streamer = Streamer::new([]);
+        let mut source = StubSource::new(Vec::new()).lagging();
+        let (writers, _receiver) = channel();
+
+        let finished = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
 
-        assert_eq!(finished, false);
+        assert!(!finished);
+    }
+
+    #[test]
+    fn idle_when_source_has_no_message() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(0);
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 1500)]);
+        let (writers, _receiver) = channel();
+
+        streamer.process(&mut source, &start_time, None, &writers, &wall_clock);
+        let after_message = streamer.is_idle();
+        streamer.process(&mut source, &start_time, None, &writers, &wall_clock);
+
+        assert!(!after_message);
+        assert!(streamer.is_idle());
     }
 
     #[test]
@@ -111,15 +523,13 @@ mod tests {
         let wall_clock = generate_wall_clock(vec![1900]);
         let start_time = to_system_time(0);
         let stop_time = None;
-        let streamer = Streamer {};
-        let mut source = StubSource {
-            data: Vec::new(),
-            index: 0,
-        };
+        let mut streamer = Streamer::new([]);
+        let mut source = StubSource::new(Vec::new());
+        let (writers, _receiver) = channel();
 
-        let finished = streamer.process(&mut source, &start_time, stop_time, &wall_clock);
+        let finished = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
 
-        assert_eq!(finished, false);
+        assert!(!finished);
     }
 
     #[test]
@@ -127,21 +537,295 @@ mod tests {
         let wall_clock = generate_wall_clock(vec![3000]);
         let start_time = to_system_time(0);
         let stop_time = Some(to_system_time(2000));
-        let streamer = Streamer {};
-        let mut source = StubSource {
-            data: vec!["data".to_owned()],
-            index: 0,
-        };
+        let mut streamer = Streamer::new([]);
+        let mut source = StubSource::new(vec![Message {
+            timestamp: to_system_time(1000),
+            data: b"data".to_vec(),
+        }]);
+        let (writers, _receiver) = channel();
 
-        let finished = streamer.process(&mut source, &start_time, stop_time, &wall_clock);
+        let finished = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
 
-        assert_eq!(finished, false);
+        assert!(!finished);
     }
 
     #[test]
     fn is_finished_if_writers_are_finished() {
-        // TODO: Data should look like (timestamp, schema, source, flatbuffer blob) 
-        // Note: Flatbuffers let's us get the source without deserialising the whole thing.
-        assert_eq!(false, true);
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(0);
+        let stop_time = Some(to_system_time(2000));
+        let mut streamer =
+            Streamer::new([SourceKey::new("ev44", "a"), SourceKey::new("ev44", "b")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 2001), ev44_message("b", 2002)]);
+        let (writers, _receiver) = channel();
+
+        let first = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+        let second = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+
+        assert!(!first);
+        assert!(second);
+    }
+
+    #[test]
+    fn later_stop_time_means_sources_are_not_finished() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(0);
+        let mut streamer =
+            Streamer::new([SourceKey::new("ev44", "a"), SourceKey::new("ev44", "b")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 2001), ev44_message("b", 2002)]);
+        let (writers, receiver) = channel();
+
+        let first = streamer.process(
+            &mut source,
+            &start_time,
+            Some(to_system_time(2000)),
+            &writers,
+            &wall_clock,
+        );
+        let second = streamer.process(
+            &mut source,
+            &start_time,
+            Some(to_system_time(3000)),
+            &writers,
+            &wall_clock,
+        );
+
+        assert!(!first);
+        assert!(!second);
+        // The message after the old stop time is kept rather than dropped.
+        assert_eq!(receiver.try_recv().unwrap().timestamp, to_system_time(2001));
+        assert_eq!(receiver.try_recv().unwrap().timestamp, to_system_time(2002));
+    }
+
+    #[test]
+    fn finished_when_other_sources_are_past_stop_time() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(0);
+        let stop_time = Some(to_system_time(2000));
+        // "a" only sends when it changes, while "b" isn't written by this job.
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![
+            ev44_message("a", 1500),
+            ev44_message("b", 2005),
+            ev44_message("b", 2020),
+            ev44_message("b", 2030),
+        ])
+        .lagging();
+        let (writers, _receiver) = channel();
+
+        let finished: Vec<_> = (0..4)
+            .map(|_| streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock))
+            .collect();
+
+        assert_eq!(finished, [false, false, true, true]);
+        assert_eq!(source.index, 3);
+    }
+
+    #[test]
+    fn earlier_stop_time_keeps_finished_sources() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(0);
+        let mut streamer =
+            Streamer::new([SourceKey::new("ev44", "a"), SourceKey::new("ev44", "b")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 2001), ev44_message("b", 1902)]);
+        let (writers, _receiver) = channel();
+
+        let first = streamer.process(
+            &mut source,
+            &start_time,
+            Some(to_system_time(2000)),
+            &writers,
+            &wall_clock,
+        );
+        let second = streamer.process(
+            &mut source,
+            &start_time,
+            Some(to_system_time(1901)),
+            &writers,
+            &wall_clock,
+        );
+
+        assert!(!first);
+        assert!(second);
+    }
+
+    #[test]
+    fn stop_time_in_the_past_still_writes_the_backlog() {
+        let wall_clock = generate_wall_clock(vec![5000]);
+        let start_time = to_system_time(1000);
+        let stop_time = Some(to_system_time(2000));
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 1500)]);
+        let (writers, receiver) = channel();
+
+        let first = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+        let second = streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+
+        assert!(!first);
+        assert!(second);
+        assert_eq!(receiver.try_recv().unwrap().timestamp, to_system_time(1500));
+    }
+
+    #[test]
+    fn messages_in_run_are_sent_to_writer() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(1000);
+        let stop_time = Some(to_system_time(2000));
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 1500)]);
+        let (writers, receiver) = channel();
+
+        streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+
+        let message = receiver.try_recv().unwrap();
+        assert_eq!(message.key, SourceKey::new("ev44", "a"));
+        assert_eq!(message.timestamp, to_system_time(1500));
+    }
+
+    #[test]
+    fn messages_outside_run_or_for_other_sources_are_not_sent() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(1000);
+        let stop_time = Some(to_system_time(2000));
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![
+            ev44_message("a", 500),
+            ev44_message("b", 1500),
+            ev44_message("a", 2500),
+        ]);
+        let (writers, receiver) = channel();
+
+        for _ in 0..3 {
+            streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+        }
+
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn messages_outside_run_are_counted_as_dropped() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(1000);
+        let stop_time = Some(to_system_time(2000));
+        let stats = JobStats::default();
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]).with_stats(stats.clone());
+        let mut source = StubSource::new(vec![
+            ev44_message("a", 500),
+            ev44_message("b", 1500),
+            ev44_message("a", 1500),
+            ev44_message("a", 2500),
+        ]);
+        let (writers, _receiver) = channel();
+
+        for _ in 0..4 {
+            streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+        }
+        streamer.close();
+
+        let counted = stats.get(&SourceKey::new("ev44", "a"));
+        assert_eq!(counted.messages_received, 3);
+        assert_eq!(counted.messages_dropped, 2);
+        assert_eq!(stats.get(&SourceKey::new("ev44", "b")).messages_received, 0);
+    }
+
+    #[test]
+    fn invalid_messages_are_counted_as_dropped() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(1000);
+        let stop_time = Some(to_system_time(2000));
+        let stats = JobStats::default();
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]).with_stats(stats.clone());
+        let not_monotonic = Ev44Builder::new("a")
+            .pulse(1_000_000_000, &[1, 2], &[1, 2])
+            .pulse(2_000_000_000, &[3], &[3])
+            .pulse(3_000_000_000, &[], &[])
+            .reference_time_index(&[0, 2, 1])
+            .build();
+        let mut source = StubSource::new(vec![Message {
+            timestamp: to_system_time(1500),
+            data: not_monotonic,
+        }]);
+        let (writers, receiver) = channel();
+
+        streamer.process(&mut source, &start_time, stop_time, &writers, &wall_clock);
+
+        let counted = stats.get(&SourceKey::new("ev44", "a"));
+        assert_eq!(counted.messages_received, 1);
+        assert_eq!(counted.messages_dropped, 1);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn source_of_invalid_message_can_be_identified() {
+        let data = Ev44Builder::new("detector")
+            .pulse(1, &[1], &[1])
+            .reference_time_index(&[5])
+            .build();
+
+        assert!(Ev44::from_bytes(&data).is_err());
+        assert_eq!(identify(&data), Some(SourceKey::new("ev44", "detector")));
+    }
+
+    #[test]
+    fn truncated_message_is_not_identified() {
+        let data = Ev44Builder::new("detector").pulse(1, &[1], &[1]).build();
+
+        for length in 0..data.len() / 2 {
+            assert_eq!(identify(&data[..length]), None, "{length}");
+        }
+    }
+
+    #[test]
+    fn finished_if_writer_thread_has_gone() {
+        let wall_clock = generate_wall_clock(vec![1900]);
+        let start_time = to_system_time(1000);
+        let mut streamer = Streamer::new([SourceKey::new("ev44", "a")]);
+        let mut source = StubSource::new(vec![ev44_message("a", 1500)]);
+        let (writers, receiver) = channel();
+        drop(receiver);
+
+        let finished = streamer.process(&mut source, &start_time, None, &writers, &wall_clock);
+
+        assert!(finished);
+    }
+
+    #[test]
+    fn capture_source_replays_only_its_topic() {
+        let dir = TempDir::new("capture_source");
+        let path = dir.join("run.cap");
+        let messages = [
+            ("detector", ev44_message("a", 1)),
+            ("motion", ev44_message("b", 2)),
+            ("detector", ev44_message("a", 3)),
+        ];
+        let topics = ["detector".to_owned(), "motion".to_owned()];
+        let mut writer =
+            crate::capture::CaptureWriter::new(File::create(&path).unwrap(), &topics).unwrap();
+        for (topic, message) in &messages {
+            writer
+                .write(&crate::capture::CaptureRecord {
+                    timestamp: message.timestamp,
+                    topic: topic.to_string(),
+                    partition: 0,
+                    offset: 0,
+                    schema: "ev44".to_owned(),
+                    source: String::new(),
+                    data: message.data.clone(),
+                })
+                .unwrap();
+        }
+        drop(writer);
+
+        let mut source = CaptureSource::open(&path, "detector").unwrap();
+        let replayed = [source.poll(), source.poll(), source.poll()];
+
+        assert_eq!(
+            replayed,
+            [
+                Some(messages[0].1.clone()),
+                Some(messages[2].1.clone()),
+                None
+            ]
+        );
     }
 }
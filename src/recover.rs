@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use log::warn;
+
+use crate::journal::{journal_path, Journal, JournalError};
+use crate::output::complete_path;
+
+/// A dataset which was longer than the journal says was flushed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trim {
+    pub path: String,
+    pub length: usize,
+    pub journaled: usize,
+}
+
+/// What was done to an interrupted file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recovered {
+    /// Where the file is now; partial files are given their final name.
+    pub path: PathBuf,
+    pub trims: Vec<Trim>,
+}
+
+#[derive(Debug)]
+pub enum RecoverError {
+    Journal(JournalError),
+    Hdf5(hdf5::Error),
+    /// Couldn't give a partial file its final name.
+    Rename(PathBuf, std::io::Error),
+}
+
+impl std::fmt::Display for RecoverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoverError::Journal(err) => write!(f, "{err}"),
+            RecoverError::Hdf5(err) => write!(f, "HDF5 error: {err}"),
+            RecoverError::Rename(path, err) => {
+                write!(f, "could not rename to {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecoverError {}
+
+impl From<JournalError> for RecoverError {
+    fn from(err: JournalError) -> Self {
+        RecoverError::Journal(err)
+    }
+}
+
+impl From<hdf5::Error> for RecoverError {
+    fn from(err: hdf5::Error) -> Self {
+        RecoverError::Hdf5(err)
+    }
+}
+
+/// Works out which datasets have to be cut back to their journaled lengths, given
+/// their current lengths.
+///
+/// Anything past the journaled length may have been written by one writer but not
+/// its partner, e.g. event ids without their time offsets.
+pub fn trims(journal: &Journal, lengths: &BTreeMap<String, usize>) -> Vec<Trim> {
+    journal
+        .datasets
+        .iter()
+        .filter_map(|(path, &journaled)| {
+            let Some(&length) = lengths.get(path) else {
+                warn!("{path} is in the journal but not the file");
+                return None;
+            };
+            if length < journaled {
+                warn!("{path} has {length} values but the journal has {journaled}");
+            }
+            (length > journaled).then(|| Trim {
+                path: path.clone(),
+                length,
+                journaled,
+            })
+        })
+        .collect()
+}
+
+/// Makes a file left behind by an interrupted job consistent again, by trimming its
+/// streamed datasets to the lengths in its journal. The journal is removed
+/// afterwards, and a partial file is renamed unless that would replace another file.
+pub fn recover(path: &Path) -> Result<Recovered, RecoverError> {
+    let journal_file = journal_path(path);
+    let journal = Journal::read(&journal_file)?;
+    let file = hdf5::File::open_rw(path)?;
+    let mut lengths = BTreeMap::new();
+    for name in journal.datasets.keys() {
+        if let Ok(dataset) = file.dataset(name) {
+            lengths.insert(name.clone(), dataset.shape().first().copied().unwrap_or(0));
+        }
+    }
+    let trims = trims(&journal, &lengths);
+    for trim in &trims {
+        file.dataset(&trim.path)?.resize(trim.journaled)?;
+    }
+    file.close()?;
+    if let Err(err) = std::fs::remove_file(&journal_file) {
+        warn!("could not remove journal {}: {err}", journal_file.display());
+    }
+    let path = match complete_path(path) {
+        Some(complete) if complete.exists() => {
+            warn!(
+                "not renaming {} as {} exists",
+                path.display(),
+                complete.display()
+            );
+            path.to_owned()
+        }
+        Some(complete) => {
+            std::fs::rename(path, &complete)
+                .map_err(|err| RecoverError::Rename(complete.clone(), err))?;
+            complete
+        }
+        None => path.to_owned(),
+    };
+    Ok(Recovered { path, trims })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lengths(lengths: &[(&str, usize)]) -> BTreeMap<String, usize> {
+        lengths
+            .iter()
+            .map(|(path, length)| (path.to_string(), *length))
+            .collect()
+    }
+
+    #[test]
+    fn datasets_past_the_journal_are_trimmed() {
+        let journal = Journal {
+            datasets: lengths(&[("/entry/log/time", 10), ("/entry/log/value", 10)]),
+        };
+
+        let trims = trims(
+            &journal,
+            &lengths(&[("/entry/log/time", 10), ("/entry/log/value", 12)]),
+        );
+
+        assert_eq!(
+            trims,
+            vec![Trim {
+                path: "/entry/log/value".to_owned(),
+                length: 12,
+                journaled: 10,
+            }]
+        );
+    }
+
+    #[test]
+    fn short_or_missing_datasets_are_left_alone() {
+        let journal = Journal {
+            datasets: lengths(&[("/entry/log/time", 10), ("/entry/log/value", 10)]),
+        };
+
+        let trims = trims(&journal, &lengths(&[("/entry/log/time", 8)]));
+
+        assert!(trims.is_empty());
+    }
+}
@@ -0,0 +1,790 @@
+use std::path::Path;
+use std::time::{Instant, SystemTime};
+
+use log::{error, info, warn};
+use rdkafka::error::KafkaResult;
+use serde_json::Value;
+
+use crate::answ_action_response_generated::ActionType;
+use crate::cli::{PartialFiles, ServiceSettings};
+use crate::job::{Job, JobError, JobOptions};
+use crate::journal::journal_path;
+use crate::json::{format_time, generate_structure};
+use crate::kafka::{from_millis, to_millis, KafkaSource};
+use crate::manager::{JobManager, RunningJob};
+use crate::nexus::NexusStructure;
+use crate::output::{find_partial_files, partial_path, FileOptions, OutputFile};
+use crate::paths::{output_path, prepare_output, PathError};
+use crate::pl72_run_start_generated::root_as_run_start;
+use crate::process::{Source, Time, WallClock};
+use crate::recover::recover;
+use crate::run_stop_6s4t_generated::root_as_run_stop;
+use crate::signals;
+use crate::status::{CommandResponse, JobFinished, ServiceInfo, StatusReporter};
+use crate::template::{
+    resolve_includes, substitute, IncludeError, UnresolvedPlaceholders, Variables,
+};
+use crate::values::{load_external_values, ValuesError};
+
+/// A command sent to the command topic.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobCommand {
+    Start(StartCommand),
+    Stop(StopCommand),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StartCommand {
+    pub job_id: String,
+    pub filename: String,
+    /// None means start from now.
+    pub start_time: Option<SystemTime>,
+    /// None means write until told to stop.
+    pub stop_time: Option<SystemTime>,
+    pub nexus_structure: String,
+    pub broker: Option<String>,
+    pub service_id: Option<String>,
+    pub run_name: Option<String>,
+    /// JSON describing the run, e.g. its proposal and run number.
+    pub metadata: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StopCommand {
+    pub job_id: String,
+    /// None means stop now.
+    pub stop_time: Option<SystemTime>,
+    pub service_id: Option<String>,
+}
+
+impl JobCommand {
+    /// Whether the command is meant for the given service. Commands without a
+    /// service id are for anyone.
+    pub fn is_for(&self, service_id: &str) -> bool {
+        let target = match self {
+            JobCommand::Start(start) => &start.service_id,
+            JobCommand::Stop(stop) => &stop.service_id,
+        };
+        target.as_deref().is_none_or(|target| target == service_id)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CommandError {
+    InvalidMessage(flatbuffers::InvalidFlatbuffer),
+    MissingField(&'static str),
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::InvalidMessage(err) => write!(f, "invalid command: {err}"),
+            CommandError::MissingField(field) => write!(f, "command has no {field}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<flatbuffers::InvalidFlatbuffer> for CommandError {
+    fn from(err: flatbuffers::InvalidFlatbuffer) -> Self {
+        CommandError::InvalidMessage(err)
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value.filter(|v| !v.is_empty()).map(str::to_owned)
+}
+
+fn optional_time(millis: u64) -> Option<SystemTime> {
+    (millis != 0).then(|| from_millis(millis))
+}
+
+/// Reads a pl72 or 6s4t command; anything else on the command topic is ignored.
+pub fn parse_command(buf: &[u8]) -> Result<Option<JobCommand>, CommandError> {
+    match buf.get(4..8) {
+        Some(b"pl72") => {
+            let start = root_as_run_start(buf)?;
+            Ok(Some(JobCommand::Start(StartCommand {
+                job_id: non_empty(start.job_id()).ok_or(CommandError::MissingField("job_id"))?,
+                filename: non_empty(start.filename())
+                    .ok_or(CommandError::MissingField("filename"))?,
+                start_time: optional_time(start.start_time()),
+                stop_time: optional_time(start.stop_time()),
+                nexus_structure: non_empty(start.nexus_structure())
+                    .ok_or(CommandError::MissingField("nexus_structure"))?,
+                broker: non_empty(start.broker()),
+                service_id: non_empty(start.service_id()),
+                run_name: non_empty(start.run_name()),
+                metadata: non_empty(start.metadata()),
+            })))
+        }
+        Some(b"6s4t") => {
+            let stop = root_as_run_stop(buf)?;
+            Ok(Some(JobCommand::Stop(StopCommand {
+                job_id: non_empty(stop.job_id()).ok_or(CommandError::MissingField("job_id"))?,
+                stop_time: optional_time(stop.stop_time()),
+                service_id: non_empty(stop.service_id()),
+            })))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[derive(Debug)]
+pub enum StartError {
+    InvalidStructure(serde_json::Error),
+    Include(IncludeError),
+    Values(ValuesError),
+    Placeholders(UnresolvedPlaceholders),
+    Path(PathError),
+    Job(JobError),
+}
+
+impl std::fmt::Display for StartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartError::InvalidStructure(err) => write!(f, "invalid nexus_structure: {err}"),
+            StartError::Include(err) => write!(f, "{err}"),
+            StartError::Values(err) => write!(f, "{err}"),
+            StartError::Placeholders(err) => write!(f, "{err}"),
+            StartError::Path(err) => write!(f, "{err}"),
+            StartError::Job(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for StartError {}
+
+impl From<serde_json::Error> for StartError {
+    fn from(err: serde_json::Error) -> Self {
+        StartError::InvalidStructure(err)
+    }
+}
+
+impl From<IncludeError> for StartError {
+    fn from(err: IncludeError) -> Self {
+        StartError::Include(err)
+    }
+}
+
+impl From<ValuesError> for StartError {
+    fn from(err: ValuesError) -> Self {
+        StartError::Values(err)
+    }
+}
+
+impl From<UnresolvedPlaceholders> for StartError {
+    fn from(err: UnresolvedPlaceholders) -> Self {
+        StartError::Placeholders(err)
+    }
+}
+
+impl From<PathError> for StartError {
+    fn from(err: PathError) -> Self {
+        StartError::Path(err)
+    }
+}
+
+impl From<JobError> for StartError {
+    fn from(err: JobError) -> Self {
+        StartError::Job(err)
+    }
+}
+
+impl From<hdf5::Error> for StartError {
+    fn from(err: hdf5::Error) -> Self {
+        StartError::Job(JobError::Hdf5(err))
+    }
+}
+
+/// Creates the file from the nexus_structure and starts writing the data into it.
+///
+/// `connect` creates the consumer for each topic, as for [`Job::start`].
+pub fn start_job<F, E>(
+    path: &Path,
+    nexus_structure: &NexusStructure,
+    start_time: SystemTime,
+    stop_time: Option<SystemTime>,
+    file_options: FileOptions,
+    options: JobOptions,
+    connect: F,
+) -> Result<Job, StartError>
+where
+    F: FnMut(&str) -> Result<Box<dyn Source + Send>, E>,
+    E: std::fmt::Display,
+{
+    let file = OutputFile::create(path, file_options)?;
+    let result = start_writing(
+        file,
+        nexus_structure,
+        start_time,
+        stop_time,
+        options,
+        connect,
+    );
+    if result.is_err() {
+        // Otherwise the leftover partial file would stop the job being retried.
+        discard_partial_file(path);
+    }
+    result
+}
+
+fn start_writing<F, E>(
+    mut file: OutputFile,
+    nexus_structure: &NexusStructure,
+    start_time: SystemTime,
+    stop_time: Option<SystemTime>,
+    options: JobOptions,
+    connect: F,
+) -> Result<Job, StartError>
+where
+    F: FnMut(&str) -> Result<Box<dyn Source + Send>, E>,
+    E: std::fmt::Display,
+{
+    let modules = generate_structure(nexus_structure, &mut file)?;
+    Ok(Job::start(
+        file, &modules, start_time, stop_time, options, connect,
+    )?)
+}
+
+/// Removes the partial file for `path` and its journal, if there are any.
+fn discard_partial_file(path: &Path) {
+    let partial = partial_path(path);
+    for file in [journal_path(&partial), partial] {
+        match std::fs::remove_file(&file) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => warn!("could not remove {}: {err}", file.display()),
+        }
+    }
+}
+
+/// The variables the file name template and the nexus_structure can use for a job.
+///
+/// The top-level strings and numbers in the metadata are available by their
+/// upper-cased names, e.g. "proposal" as `$PROPOSAL$`, along with `$JOB_ID$`,
+/// `$FILENAME$`, `$RUN_NAME$` and the start `$DATE$`.
+pub fn job_variables(command: &StartCommand, start_time: SystemTime) -> Variables {
+    let mut variables = Variables::new();
+    let metadata = command
+        .metadata
+        .as_deref()
+        .map(serde_json::from_str::<Value>);
+    match metadata {
+        Some(Ok(Value::Object(metadata))) => {
+            for (name, value) in metadata {
+                let value = match value {
+                    Value::String(s) => s,
+                    Value::Number(n) => n.to_string(),
+                    _ => continue,
+                };
+                variables.insert(name.to_uppercase(), value);
+            }
+        }
+        Some(_) => warn!(
+            "ignoring metadata for job {} as it isn't a JSON object",
+            command.job_id
+        ),
+        None => {}
+    }
+    variables.insert("JOB_ID".to_owned(), command.job_id.clone());
+    variables.insert("FILENAME".to_owned(), command.filename.clone());
+    if let Some(run_name) = &command.run_name {
+        variables.insert("RUN_NAME".to_owned(), run_name.clone());
+    }
+    variables.insert("DATE".to_owned(), format_time(start_time)[..10].to_owned());
+    variables
+}
+
+/// Reads a start command's nexus_structure, pulling in its includes and external
+/// values and filling in its placeholders from `variables`.
+pub fn job_structure(
+    command: &StartCommand,
+    template_dir: &Path,
+    variables: &Variables,
+) -> Result<NexusStructure, StartError> {
+    let structure = serde_json::from_str(&command.nexus_structure)?;
+    let structure = resolve_includes(&structure, template_dir)?;
+    let structure = load_external_values(&structure, template_dir)?;
+    let structure = substitute(&structure, variables)?;
+    Ok(serde_json::from_value(structure)?)
+}
+
+/// When a stop command means the job should stop; without a stop time that is now.
+pub fn stop_time_for<T: Time>(command: &StopCommand, clock: &T) -> SystemTime {
+    command.stop_time.unwrap_or_else(|| clock.now())
+}
+
+/// Connects to a topic on the broker, reading from the start time.
+pub fn kafka_connector(
+    broker: &str,
+    start_time: SystemTime,
+) -> impl FnMut(&str) -> KafkaResult<Box<dyn Source + Send>> + '_ {
+    move |topic| {
+        KafkaSource::new(broker, topic, start_time)
+            .map(|source| Box::new(source) as Box<dyn Source + Send>)
+    }
+}
+
+/// Deals with the files a previous run didn't finish writing, e.g. because it
+/// crashed.
+fn clean_up_partial_files(dir: &Path, policy: PartialFiles) {
+    let files = match find_partial_files(dir) {
+        Ok(files) => files,
+        Err(err) => {
+            warn!(
+                "could not look for partial files in {}: {err}",
+                dir.display()
+            );
+            return;
+        }
+    };
+    for path in files {
+        match policy {
+            PartialFiles::Keep => warn!("leaving partial file {}", path.display()),
+            PartialFiles::Delete => {
+                info!("deleting partial file {}", path.display());
+                if let Err(err) = std::fs::remove_file(&path) {
+                    warn!("could not delete {}: {err}", path.display());
+                }
+                let _ = std::fs::remove_file(journal_path(&path));
+            }
+            PartialFiles::Recover => match recover(&path) {
+                Ok(recovered) => info!(
+                    "recovered {} as {}",
+                    path.display(),
+                    recovered.path.display()
+                ),
+                Err(err) => warn!("could not recover {}: {err}", path.display()),
+            },
+        }
+    }
+}
+
+/// Listens for commands and runs up to `max_jobs` jobs at a time, reporting their
+/// status as it goes.
+pub struct Service {
+    settings: ServiceSettings,
+    commands: KafkaSource,
+    status: StatusReporter,
+    jobs: JobManager<Job>,
+}
+
+impl Service {
+    pub fn new(settings: ServiceSettings) -> KafkaResult<Service> {
+        clean_up_partial_files(&settings.output_dir, settings.partial_files);
+        // Old commands are ignored, otherwise a restart would re-run them.
+        let commands =
+            KafkaSource::new(&settings.broker, &settings.command_topic, SystemTime::now())?;
+        let status = StatusReporter::new(
+            &settings.broker,
+            &settings.status_topic,
+            ServiceInfo::new(&settings.service_id),
+            settings.status_interval,
+        )?;
+        let jobs = JobManager::new(settings.max_jobs);
+        Ok(Service {
+            settings,
+            commands,
+            status,
+            jobs,
+        })
+    }
+
+    /// Handles commands until the process is told to stop, then closes any files
+    /// being written.
+    pub fn run(&mut self) {
+        info!(
+            "{} listening for commands on {}",
+            self.settings.service_id, self.settings.command_topic
+        );
+        loop {
+            if let Some(message) = self.commands.poll() {
+                self.handle_message(&message.data);
+            }
+            if signals::stop_requested() {
+                self.shutdown();
+                return;
+            }
+            self.finish_jobs_if_done();
+            let status = self
+                .jobs
+                .status_json(|job| job.stats().to_json())
+                .to_string();
+            if let Err(err) = self.status.publish_if_due(Instant::now(), &status) {
+                warn!("could not send status: {err}");
+            }
+        }
+    }
+
+    fn handle_message(&mut self, buf: &[u8]) {
+        match parse_command(buf) {
+            Ok(Some(command)) if command.is_for(&self.settings.service_id) => match command {
+                JobCommand::Start(start) => self.handle_start(start),
+                JobCommand::Stop(stop) => self.handle_stop(stop),
+            },
+            Ok(_) => {}
+            Err(err) => warn!("ignoring command: {err}"),
+        }
+    }
+
+    fn handle_start(&mut self, command: StartCommand) {
+        let start_time = command.start_time.unwrap_or_else(SystemTime::now);
+        let variables = job_variables(&command, start_time);
+        let path = output_path(
+            &self.settings.output_dir,
+            &self.settings.filename_template,
+            &variables,
+        );
+        let path = match path {
+            Ok(path) => path,
+            Err(err) => return self.refuse_start(&command.job_id, err),
+        };
+        if let Err(err) = self.jobs.can_start(&command.job_id, &path) {
+            return self.refuse_start(&command.job_id, err);
+        }
+        let broker = command.broker.as_deref().unwrap_or(&self.settings.broker);
+        info!("starting job {} writing {}", command.job_id, path.display());
+        let result = prepare_output(&path, self.settings.overwrite, self.settings.min_free_bytes)
+            .map_err(StartError::from)
+            .and_then(|()| job_structure(&command, &self.settings.template_dir, &variables))
+            .and_then(|structure| {
+                start_job(
+                    &path,
+                    &structure,
+                    start_time,
+                    command.stop_time,
+                    FileOptions {
+                        swmr: self.settings.swmr,
+                        ..FileOptions::default()
+                    },
+                    JobOptions {
+                        stats_attributes: self.settings.stats_attributes,
+                        ..JobOptions::default()
+                    },
+                    kafka_connector(broker, start_time),
+                )
+            });
+        let response = match result {
+            Ok(job) => {
+                let running = RunningJob {
+                    job_id: command.job_id.clone(),
+                    path,
+                    start_time,
+                    stop_time: command.stop_time,
+                    job,
+                };
+                // Nothing else can have started in the meantime, so this was checked above.
+                if let Err(err) = self.jobs.add(running) {
+                    error!("lost track of job {}: {err}", command.job_id);
+                }
+                CommandResponse {
+                    stop_time: command.stop_time,
+                    ..CommandResponse::success(&command.job_id, ActionType::StartJob)
+                }
+            }
+            Err(err) => {
+                error!("could not start job {}: {err}", command.job_id);
+                CommandResponse::failure(&command.job_id, ActionType::StartJob, err.to_string())
+            }
+        };
+        self.respond(response);
+    }
+
+    fn refuse_start(&mut self, job_id: &str, err: impl std::fmt::Display) {
+        warn!("refusing job {job_id}: {err}");
+        self.respond(CommandResponse::failure(
+            job_id,
+            ActionType::StartJob,
+            err.to_string(),
+        ));
+    }
+
+    fn handle_stop(&mut self, command: StopCommand) {
+        let Some(running) = self.jobs.get_mut(&command.job_id) else {
+            warn!("ignoring stop for unknown job {}", command.job_id);
+            self.respond(CommandResponse::failure(
+                &command.job_id,
+                ActionType::SetStopTime,
+                "no such job".to_owned(),
+            ));
+            return;
+        };
+        let stop_time = stop_time_for(&command, &WallClock);
+        if !running.job.set_stop_time(stop_time) {
+            warn!("job {} has already finished", command.job_id);
+            self.respond(CommandResponse::failure(
+                &command.job_id,
+                ActionType::SetStopTime,
+                "job has already finished".to_owned(),
+            ));
+            return;
+        }
+        info!(
+            "job {} will stop at {}",
+            command.job_id,
+            to_millis(stop_time)
+        );
+        running.stop_time = Some(stop_time);
+        self.respond(CommandResponse {
+            stop_time: Some(stop_time),
+            ..CommandResponse::success(&command.job_id, ActionType::SetStopTime)
+        });
+    }
+
+    fn respond(&mut self, response: CommandResponse) {
+        if let Err(err) = self.status.respond(&response) {
+            warn!(
+                "could not respond to command for job {}: {err}",
+                response.job_id
+            );
+        }
+    }
+
+    /// Stops all the jobs and waits for their files to be closed.
+    fn shutdown(&mut self) {
+        info!("stopping {} jobs before exiting", self.jobs.jobs().len());
+        for running in self.jobs.jobs() {
+            running.job.interrupt();
+        }
+        self.finish_jobs(|_| true);
+    }
+
+    fn finish_jobs_if_done(&mut self) {
+        self.finish_jobs(Job::is_finished);
+    }
+
+    fn finish_jobs(&mut self, is_finished: impl Fn(&Job) -> bool) {
+        for finished in self.jobs.take_finished(is_finished) {
+            let (file_name, error) = match finished.job.join() {
+                Ok(path) => {
+                    info!(
+                        "job {} finished writing {}",
+                        finished.job_id,
+                        path.display()
+                    );
+                    (path, None)
+                }
+                Err(err) => {
+                    error!("job {} failed: {err}", finished.job_id);
+                    (partial_path(&finished.path), Some(err.to_string()))
+                }
+            };
+            let done = JobFinished {
+                job_id: finished.job_id,
+                file_name,
+                error,
+            };
+            if let Err(err) = self.status.finished(&done) {
+                warn!("could not report job {} finished: {err}", done.job_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::fixtures::{RunStartBuilder, RunStopBuilder, TempDir};
+    use crate::nexus::{NexusNode, Values};
+
+    use super::*;
+
+    #[test]
+    fn parse_start_command() {
+        let buf = RunStartBuilder::new("job_1")
+            .filename("file.nxs")
+            .start_time(1000)
+            .nexus_structure("{}")
+            .service_id("writer_1")
+            .build();
+
+        let Some(JobCommand::Start(start)) = parse_command(&buf).unwrap() else {
+            panic!("expected start command");
+        };
+
+        assert_eq!(start.job_id, "job_1");
+        assert_eq!(start.filename, "file.nxs");
+        assert_eq!(
+            start.start_time,
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1))
+        );
+        assert_eq!(start.stop_time, None);
+        assert_eq!(start.broker, None);
+    }
+
+    #[test]
+    fn job_variables_come_from_the_command_and_metadata() {
+        let buf = RunStartBuilder::new("job_1")
+            .filename("file.nxs")
+            .nexus_structure("{}")
+            .run_name("run_7")
+            .metadata(r#"{"proposal": 12345, "run_number": 7, "sample": {"name": "x"}}"#)
+            .build();
+        let Some(JobCommand::Start(start)) = parse_command(&buf).unwrap() else {
+            panic!("expected start command");
+        };
+
+        let variables = job_variables(
+            &start,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_709_294_400),
+        );
+
+        assert_eq!(variables["PROPOSAL"], "12345");
+        assert_eq!(variables["RUN_NUMBER"], "7");
+        assert_eq!(variables["JOB_ID"], "job_1");
+        assert_eq!(variables["FILENAME"], "file.nxs");
+        assert_eq!(variables["RUN_NAME"], "run_7");
+        assert_eq!(variables["DATE"], "2024-03-01");
+        assert!(!variables.contains_key("SAMPLE"));
+    }
+
+    fn start_with_title(metadata: &str) -> StartCommand {
+        let buf = RunStartBuilder::new("job_1")
+            .filename("file.nxs")
+            .nexus_structure(
+                r#"{"children": [{"module": "dataset", "config": {"name": "title", "dtype": "string", "values": "$TITLE$"}}]}"#,
+            )
+            .metadata(metadata)
+            .build();
+        let Some(JobCommand::Start(start)) = parse_command(&buf).unwrap() else {
+            panic!("expected start command");
+        };
+        start
+    }
+
+    #[test]
+    fn placeholders_in_start_command_structure_are_filled_in() {
+        let start = start_with_title(r#"{"title": "Beam test"}"#);
+        let variables = job_variables(&start, SystemTime::UNIX_EPOCH);
+
+        let structure = job_structure(&start, Path::new("."), &variables).unwrap();
+
+        let NexusNode::Dataset(title) = &structure.children[0] else {
+            panic!("expected dataset");
+        };
+        assert_eq!(title.values, Values::String("Beam test".to_owned()));
+    }
+
+    #[test]
+    fn missing_placeholder_refuses_the_start() {
+        let start = start_with_title("{}");
+        let variables = job_variables(&start, SystemTime::UNIX_EPOCH);
+
+        let result = job_structure(&start, Path::new("."), &variables);
+
+        assert!(
+            matches!(result, Err(StartError::Placeholders(UnresolvedPlaceholders(names))) if names == ["TITLE"])
+        );
+    }
+
+    #[test]
+    fn failed_start_leaves_no_partial_file() {
+        let dir = TempDir::new("failed_start");
+        let path = dir.join("run.nxs");
+        let structure: NexusStructure = serde_json::from_str(
+            r#"{"children": [{"module": "f144", "config": {"dtype": "double", "source": "temperature", "topic": "motion"}}]}"#,
+        )
+        .unwrap();
+
+        let result = start_job(
+            &path,
+            &structure,
+            SystemTime::UNIX_EPOCH,
+            None,
+            FileOptions::default(),
+            JobOptions::default(),
+            |topic| Err::<Box<dyn Source + Send>, _>(format!("no broker for {topic}")),
+        );
+        let leftovers = std::fs::read_dir(&dir).unwrap().count();
+
+        assert!(matches!(
+            result,
+            Err(StartError::Job(JobError::Connect { .. }))
+        ));
+        assert_eq!(leftovers, 0);
+    }
+
+    #[test]
+    fn start_command_without_filename_is_an_error() {
+        let buf = RunStartBuilder::new("job_1").nexus_structure("{}").build();
+
+        assert_eq!(
+            parse_command(&buf),
+            Err(CommandError::MissingField("filename"))
+        );
+    }
+
+    #[test]
+    fn parse_stop_command() {
+        let buf = RunStopBuilder::new("job_1").stop_time(2000).build();
+
+        assert_eq!(
+            parse_command(&buf),
+            Ok(Some(JobCommand::Stop(StopCommand {
+                job_id: "job_1".to_owned(),
+                stop_time: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(2)),
+                service_id: None,
+            })))
+        );
+    }
+
+    struct FixedClock(SystemTime);
+
+    impl Time for FixedClock {
+        fn now(&self) -> SystemTime {
+            self.0
+        }
+    }
+
+    #[test]
+    fn stop_without_time_means_now() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(5);
+        let buf = RunStopBuilder::new("job_1").build();
+        let Some(JobCommand::Stop(stop)) = parse_command(&buf).unwrap() else {
+            panic!("expected stop command");
+        };
+
+        assert_eq!(stop_time_for(&stop, &FixedClock(now)), now);
+    }
+
+    #[test]
+    fn stop_time_is_used_even_if_in_the_past() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(5);
+        let buf = RunStopBuilder::new("job_1").stop_time(2000).build();
+        let Some(JobCommand::Stop(stop)) = parse_command(&buf).unwrap() else {
+            panic!("expected stop command");
+        };
+
+        assert_eq!(
+            stop_time_for(&stop, &FixedClock(now)),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn other_messages_are_ignored() {
+        let buf = crate::fixtures::Ev44Builder::new("source").build();
+
+        assert_eq!(parse_command(&buf), Ok(None));
+    }
+
+    #[test]
+    fn commands_can_be_for_a_specific_service() {
+        let buf = RunStopBuilder::new("job_1").service_id("writer_1").build();
+        let command = parse_command(&buf).unwrap().unwrap();
+
+        assert!(command.is_for("writer_1"));
+        assert!(!command.is_for("writer_2"));
+    }
+
+    #[test]
+    fn commands_without_service_id_are_for_everyone() {
+        let buf = RunStopBuilder::new("job_1").build();
+        let command = parse_command(&buf).unwrap().unwrap();
+
+        assert!(command.is_for("writer_1"));
+    }
+}
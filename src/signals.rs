@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether SIGINT or SIGTERM has been received since [`install`] was called.
+pub fn stop_requested() -> bool {
+    STOP_REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Catches SIGINT and SIGTERM so the open files can be closed properly before
+/// exiting, instead of the process being killed part way through a write.
+///
+/// A second signal kills the process as usual, in case closing the files hangs.
+#[cfg(unix)]
+pub fn install() -> std::io::Result<()> {
+    extern "C" fn handle(_signum: libc::c_int) {
+        // Only async-signal-safe things can be done here.
+        STOP_REQUESTED.store(true, Ordering::SeqCst);
+    }
+
+    let mut action: libc::sigaction = unsafe { std::mem::zeroed() };
+    action.sa_sigaction = handle as extern "C" fn(libc::c_int) as libc::sighandler_t;
+    // Going back to the default action after the first signal means a second one
+    // kills the process.
+    action.sa_flags = libc::SA_RESETHAND;
+    unsafe { libc::sigemptyset(&mut action.sa_mask) };
+    for signum in [libc::SIGINT, libc::SIGTERM] {
+        if unsafe { libc::sigaction(signum, &action, std::ptr::null_mut()) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Signals aren't supported, so stopping kills the process.
+#[cfg(not(unix))]
+pub fn install() -> std::io::Result<()> {
+    Ok(())
+}
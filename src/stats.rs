@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::kafka::to_millis;
+use crate::process::SourceKey;
+
+/// Counts of what happened to a source's messages, to check a file is complete.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SourceStats {
+    pub messages_received: u64,
+    pub messages_written: u64,
+    /// The size of the messages written, as received.
+    pub bytes_written: u64,
+    /// Messages outside the job's time window or which couldn't be parsed.
+    pub messages_dropped: u64,
+    /// Timestamps of the first and last messages written, in ms since the epoch.
+    pub first_timestamp: Option<i64>,
+    pub last_timestamp: Option<i64>,
+}
+
+/// The statistics for each of a job's sources, shared between its threads.
+#[derive(Clone, Debug, Default)]
+pub struct JobStats(Arc<Mutex<HashMap<SourceKey, SourceStats>>>);
+
+impl JobStats {
+    /// Starts every source at zero, so those which never get a message are still
+    /// reported.
+    pub fn new<I: IntoIterator<Item = SourceKey>>(sources: I) -> JobStats {
+        let sources = sources
+            .into_iter()
+            .map(|key| (key, SourceStats::default()))
+            .collect();
+        JobStats(Arc::new(Mutex::new(sources)))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<SourceKey, SourceStats>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn received(&self, key: &SourceKey) {
+        self.lock()
+            .entry(key.clone())
+            .or_default()
+            .messages_received += 1;
+    }
+
+    pub fn dropped(&self, key: &SourceKey) {
+        self.lock().entry(key.clone()).or_default().messages_dropped += 1;
+    }
+
+    pub fn written(&self, key: &SourceKey, bytes: usize, timestamp: SystemTime) {
+        let mut sources = self.lock();
+        let stats = sources.entry(key.clone()).or_default();
+        stats.messages_written += 1;
+        stats.bytes_written += bytes as u64;
+        let millis = to_millis(timestamp);
+        stats.first_timestamp.get_or_insert(millis);
+        stats.last_timestamp = Some(millis);
+    }
+
+    pub fn get(&self, key: &SourceKey) -> SourceStats {
+        self.lock().get(key).cloned().unwrap_or_default()
+    }
+
+    /// The statistics for every source, in order.
+    pub fn snapshot(&self) -> Vec<(SourceKey, SourceStats)> {
+        let mut sources: Vec<_> = self
+            .lock()
+            .iter()
+            .map(|(key, stats)| (key.clone(), stats.clone()))
+            .collect();
+        sources.sort_by(|(a, _), (b, _)| (&a.schema, &a.source).cmp(&(&b.schema, &b.source)));
+        sources
+    }
+
+    /// The statistics in the form used in the status messages.
+    pub fn to_json(&self) -> Value {
+        self.snapshot()
+            .into_iter()
+            .map(|(key, stats)| {
+                let mut source = json!({ "schema": key.schema, "source": key.source });
+                if let (Value::Object(source), Ok(Value::Object(stats))) =
+                    (&mut source, serde_json::to_value(stats))
+                {
+                    source.extend(stats);
+                }
+                source
+            })
+            .collect()
+    }
+}
+
+/// Records a source's statistics as attributes on the group its module writes to.
+pub fn write_attributes(group: &hdf5::Group, stats: &SourceStats) -> hdf5::Result<()> {
+    let counts = [
+        ("messages_received", stats.messages_received),
+        ("messages_written", stats.messages_written),
+        ("bytes_written", stats.bytes_written),
+        ("messages_dropped", stats.messages_dropped),
+    ];
+    for (name, count) in counts {
+        group.new_attr::<u64>().create(name)?.write_scalar(&count)?;
+    }
+    let timestamps = [
+        ("first_timestamp", stats.first_timestamp),
+        ("last_timestamp", stats.last_timestamp),
+    ];
+    for (name, timestamp) in timestamps {
+        if let Some(timestamp) = timestamp {
+            group
+                .new_attr::<i64>()
+                .create(name)?
+                .write_scalar(&timestamp)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn at(millis: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn written_messages_are_counted() {
+        let stats = JobStats::default();
+        let key = SourceKey::new("f144", "temperature");
+
+        stats.received(&key);
+        stats.received(&key);
+        stats.received(&key);
+        stats.dropped(&key);
+        stats.written(&key, 100, at(1000));
+        stats.written(&key, 50, at(2000));
+
+        assert_eq!(
+            stats.get(&key),
+            SourceStats {
+                messages_received: 3,
+                messages_written: 2,
+                bytes_written: 150,
+                messages_dropped: 1,
+                first_timestamp: Some(1000),
+                last_timestamp: Some(2000),
+            }
+        );
+    }
+
+    #[test]
+    fn statistics_are_shared_between_clones() {
+        let stats = JobStats::default();
+        let key = SourceKey::new("ev44", "detector");
+
+        stats.clone().received(&key);
+
+        assert_eq!(stats.get(&key).messages_received, 1);
+    }
+
+    #[test]
+    fn sources_without_messages_are_included() {
+        let stats = JobStats::new([SourceKey::new("f144", "pressure")]);
+
+        assert_eq!(
+            stats.snapshot(),
+            vec![(SourceKey::new("f144", "pressure"), SourceStats::default())]
+        );
+    }
+
+    #[test]
+    fn status_json_lists_sources_in_order() {
+        let stats = JobStats::default();
+        stats.received(&SourceKey::new("f144", "b"));
+        stats.written(&SourceKey::new("ev44", "a"), 10, at(5));
+
+        let json = stats.to_json();
+
+        assert_eq!(json[0]["schema"], "ev44");
+        assert_eq!(json[0]["source"], "a");
+        assert_eq!(json[0]["bytes_written"], 10);
+        assert_eq!(json[0]["first_timestamp"], 5);
+        assert_eq!(json[1]["source"], "b");
+        assert_eq!(json[1]["messages_received"], 1);
+        assert_eq!(json[1]["last_timestamp"], Value::Null);
+    }
+}
@@ -0,0 +1,306 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+use rdkafka::error::KafkaResult;
+use rdkafka::producer::{BaseProducer, BaseRecord, Producer};
+use rdkafka::ClientConfig;
+
+use crate::answ_action_response_generated::{
+    ActionOutcome, ActionResponse, ActionResponseArgs, ActionType,
+};
+use crate::kafka::to_millis;
+use crate::wrdn_finished_writing_generated::{FinishedWriting, FinishedWritingArgs};
+use crate::x5f2_status_generated::{Status, StatusArgs};
+
+/// Who is sending the status messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceInfo {
+    pub service_id: String,
+    pub host_name: String,
+    pub process_id: u32,
+}
+
+impl ServiceInfo {
+    pub fn new(service_id: &str) -> ServiceInfo {
+        ServiceInfo {
+            service_id: service_id.to_owned(),
+            host_name: host_name(),
+            process_id: std::process::id(),
+        }
+    }
+}
+
+pub fn host_name() -> String {
+    hostname::get()
+        .map(|h| h.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "unknown".to_owned())
+}
+
+/// Creates an x5f2 status message.
+pub fn create_status_message(info: &ServiceInfo, interval: Duration, status_json: &str) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
+    let software_name = Some(builder.create_string(env!("CARGO_PKG_NAME")));
+    let software_version = Some(builder.create_string(env!("CARGO_PKG_VERSION")));
+    let service_id = Some(builder.create_string(&info.service_id));
+    let host_name = Some(builder.create_string(&info.host_name));
+    let status_json = Some(builder.create_string(status_json));
+    let status = Status::create(
+        &mut builder,
+        &StatusArgs {
+            software_name,
+            software_version,
+            service_id,
+            host_name,
+            process_id: info.process_id,
+            update_interval: interval.as_millis() as u32,
+            status_json,
+        },
+    );
+    builder.finish(status, Some("x5f2"));
+    builder.finished_data().to_vec()
+}
+
+/// The reply to a start or stop command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommandResponse {
+    pub job_id: String,
+    pub action: ActionType,
+    /// Why the command failed, or None if it succeeded.
+    pub error: Option<String>,
+    pub stop_time: Option<SystemTime>,
+}
+
+impl CommandResponse {
+    pub fn success(job_id: &str, action: ActionType) -> CommandResponse {
+        CommandResponse {
+            job_id: job_id.to_owned(),
+            action,
+            error: None,
+            stop_time: None,
+        }
+    }
+
+    pub fn failure(job_id: &str, action: ActionType, error: String) -> CommandResponse {
+        CommandResponse {
+            error: Some(error),
+            ..CommandResponse::success(job_id, action)
+        }
+    }
+}
+
+/// Creates an answ message for the response to a command.
+pub fn create_response_message(info: &ServiceInfo, response: &CommandResponse) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
+    let service_id = Some(builder.create_string(&info.service_id));
+    let job_id = Some(builder.create_string(&response.job_id));
+    let message = Some(builder.create_string(response.error.as_deref().unwrap_or("")));
+    let outcome = match response.error {
+        None => ActionOutcome::Success,
+        Some(_) => ActionOutcome::Failure,
+    };
+    let answer = ActionResponse::create(
+        &mut builder,
+        &ActionResponseArgs {
+            service_id,
+            job_id,
+            action: response.action,
+            outcome,
+            stop_time: response.stop_time.map_or(0, |time| to_millis(time) as u64),
+            message,
+            ..Default::default()
+        },
+    );
+    builder.finish(answer, Some("answ"));
+    builder.finished_data().to_vec()
+}
+
+/// Sent when a job has stopped writing, whether it succeeded or not.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobFinished {
+    pub job_id: String,
+    /// The final path of the file, or where it was left if the job failed.
+    pub file_name: PathBuf,
+    pub error: Option<String>,
+}
+
+/// Creates a wrdn message saying a job has finished.
+pub fn create_finished_message(info: &ServiceInfo, finished: &JobFinished) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::with_capacity(1024);
+    let service_id = Some(builder.create_string(&info.service_id));
+    let job_id = Some(builder.create_string(&finished.job_id));
+    let file_name = Some(builder.create_string(&finished.file_name.to_string_lossy()));
+    let message = Some(builder.create_string(finished.error.as_deref().unwrap_or("")));
+    let done = FinishedWriting::create(
+        &mut builder,
+        &FinishedWritingArgs {
+            service_id,
+            job_id,
+            error_encountered: finished.error.is_some(),
+            file_name,
+            message,
+            ..Default::default()
+        },
+    );
+    builder.finish(done, Some("wrdn"));
+    builder.finished_data().to_vec()
+}
+
+/// Publishes the service's status to Kafka at a regular interval.
+pub struct StatusReporter {
+    producer: BaseProducer,
+    topic: String,
+    info: ServiceInfo,
+    interval: Duration,
+    last_sent: Option<Instant>,
+}
+
+impl StatusReporter {
+    pub fn new(
+        broker: &str,
+        topic: &str,
+        info: ServiceInfo,
+        interval: Duration,
+    ) -> KafkaResult<StatusReporter> {
+        let producer = ClientConfig::new()
+            .set("bootstrap.servers", broker)
+            .create()?;
+        Ok(StatusReporter {
+            producer,
+            topic: topic.to_owned(),
+            info,
+            interval,
+            last_sent: None,
+        })
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.last_sent
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.interval)
+    }
+
+    /// Sends the status if the interval has passed since the last one.
+    pub fn publish_if_due(&mut self, now: Instant, status_json: &str) -> KafkaResult<()> {
+        if self.is_due(now) {
+            self.publish(status_json)?;
+            self.last_sent = Some(now);
+        }
+        Ok(())
+    }
+
+    pub fn publish(&mut self, status_json: &str) -> KafkaResult<()> {
+        let message = create_status_message(&self.info, self.interval, status_json);
+        self.send(&message)
+    }
+
+    /// Sends the response to a command on the status topic.
+    pub fn respond(&mut self, response: &CommandResponse) -> KafkaResult<()> {
+        let message = create_response_message(&self.info, response);
+        self.send(&message)
+    }
+
+    /// Sends the message saying a job has finished on the status topic.
+    pub fn finished(&mut self, finished: &JobFinished) -> KafkaResult<()> {
+        let message = create_finished_message(&self.info, finished);
+        self.send(&message)
+    }
+
+    fn send(&mut self, message: &[u8]) -> KafkaResult<()> {
+        self.producer
+            .send(
+                BaseRecord::to(&self.topic)
+                    .key(&self.info.service_id)
+                    .payload(message),
+            )
+            .map_err(|(err, _)| err)?;
+        // Serves the delivery callbacks so the queue doesn't fill up.
+        self.producer.poll(Duration::ZERO);
+        Ok(())
+    }
+}
+
+impl Drop for StatusReporter {
+    fn drop(&mut self) {
+        let _ = self.producer.flush(Duration::from_secs(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answ_action_response_generated::root_as_action_response;
+    use crate::wrdn_finished_writing_generated::root_as_finished_writing;
+    use crate::x5f2_status_generated::root_as_status;
+
+    use super::*;
+
+    fn info() -> ServiceInfo {
+        ServiceInfo {
+            service_id: "writer_1".to_owned(),
+            host_name: "host".to_owned(),
+            process_id: 42,
+        }
+    }
+
+    #[test]
+    fn status_message_contents() {
+        let info = info();
+
+        let buf = create_status_message(&info, Duration::from_secs(2), r#"{"state":"idle"}"#);
+
+        let status = root_as_status(&buf).unwrap();
+        assert_eq!(status.service_id(), Some("writer_1"));
+        assert_eq!(status.host_name(), Some("host"));
+        assert_eq!(status.process_id(), 42);
+        assert_eq!(status.update_interval(), 2000);
+        assert_eq!(status.status_json(), Some(r#"{"state":"idle"}"#));
+    }
+
+    #[test]
+    fn failure_response_contents() {
+        let response = CommandResponse::failure(
+            "job_1",
+            ActionType::StartJob,
+            "already writing 2 jobs".to_owned(),
+        );
+
+        let buf = create_response_message(&info(), &response);
+
+        assert_eq!(&buf[4..8], b"answ");
+        let answer = root_as_action_response(&buf).unwrap();
+        assert_eq!(answer.service_id(), Some("writer_1"));
+        assert_eq!(answer.job_id(), Some("job_1"));
+        assert_eq!(answer.action(), ActionType::StartJob);
+        assert_eq!(answer.outcome(), ActionOutcome::Failure);
+        assert_eq!(answer.message(), Some("already writing 2 jobs"));
+    }
+
+    #[test]
+    fn success_response_has_the_stop_time() {
+        let response = CommandResponse {
+            stop_time: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(3)),
+            ..CommandResponse::success("job_1", ActionType::SetStopTime)
+        };
+
+        let buf = create_response_message(&info(), &response);
+
+        let answer = root_as_action_response(&buf).unwrap();
+        assert_eq!(answer.outcome(), ActionOutcome::Success);
+        assert_eq!(answer.stop_time(), 3000);
+    }
+
+    #[test]
+    fn finished_message_has_the_final_path() {
+        let finished = JobFinished {
+            job_id: "job_1".to_owned(),
+            file_name: PathBuf::from("/data/run_1.nxs"),
+            error: None,
+        };
+
+        let buf = create_finished_message(&info(), &finished);
+
+        assert_eq!(&buf[4..8], b"wrdn");
+        let done = root_as_finished_writing(&buf).unwrap();
+        assert_eq!(done.job_id(), Some("job_1"));
+        assert_eq!(done.file_name(), Some("/data/run_1.nxs"));
+        assert!(!done.error_encountered());
+    }
+}
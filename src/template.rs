@@ -0,0 +1,427 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+/// Values for the placeholders in a template, e.g. "TITLE" for `$TITLE$`.
+pub type Variables = HashMap<String, String>;
+
+/// The placeholders which had no value, without the `$`s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnresolvedPlaceholders(pub Vec<String>);
+
+impl std::fmt::Display for UnresolvedPlaceholders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.0.iter().map(|name| format!("${name}$")).collect();
+        write!(f, "unresolved placeholders: {}", names.join(", "))
+    }
+}
+
+impl std::error::Error for UnresolvedPlaceholders {}
+
+/// Fills in the `$NAME$` placeholders in the string values of datasets and
+/// attributes.
+///
+/// Every placeholder without a value is reported, not just the first.
+pub fn substitute(v: &Value, variables: &Variables) -> Result<Value, UnresolvedPlaceholders> {
+    let mut v = v.clone();
+    let mut unresolved = BTreeSet::new();
+    walk(&mut v, variables, &mut unresolved);
+    if unresolved.is_empty() {
+        Ok(v)
+    } else {
+        Err(UnresolvedPlaceholders(unresolved.into_iter().collect()))
+    }
+}
+
+/// Fills in the `$NAME$` placeholders in a single string, e.g. a file name.
+pub fn substitute_string(s: &str, variables: &Variables) -> Result<String, UnresolvedPlaceholders> {
+    let mut unresolved = BTreeSet::new();
+    let result = substitute_str(s, variables, &mut unresolved);
+    if unresolved.is_empty() {
+        Ok(result)
+    } else {
+        Err(UnresolvedPlaceholders(unresolved.into_iter().collect()))
+    }
+}
+
+fn walk(v: &mut Value, variables: &Variables, unresolved: &mut BTreeSet<String>) {
+    match v {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                // Both attributes and dataset configs keep their data in "values".
+                if key == "values" {
+                    substitute_values(child, variables, unresolved);
+                } else {
+                    walk(child, variables, unresolved);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk(item, variables, unresolved);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn substitute_values(v: &mut Value, variables: &Variables, unresolved: &mut BTreeSet<String>) {
+    match v {
+        Value::String(s) => *s = substitute_str(s, variables, unresolved),
+        Value::Array(items) => {
+            for item in items {
+                substitute_values(item, variables, unresolved);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Names are like identifiers, so amounts such as `$5$` aren't placeholders.
+fn is_name(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn substitute_str(s: &str, variables: &Variables, unresolved: &mut BTreeSet<String>) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('$') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('$') {
+            Some(end) if is_name(&after[..end]) => {
+                let name = &after[..end];
+                match variables.get(name) {
+                    Some(value) => result.push_str(value),
+                    None => {
+                        unresolved.insert(name.to_owned());
+                        result.push_str(&rest[start..start + end + 2]);
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            // A lone `$` isn't a placeholder.
+            _ => {
+                result.push('$');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[derive(Debug)]
+pub enum IncludeError {
+    Io(PathBuf, std::io::Error),
+    Invalid(PathBuf, serde_json::Error),
+    /// The fragment isn't a node or an array of nodes.
+    NotAFragment(PathBuf),
+    OutsideTemplateDir(PathBuf),
+    /// The chain of includes which leads back to the first one.
+    Cycle(Vec<PathBuf>),
+}
+
+impl std::fmt::Display for IncludeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IncludeError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            IncludeError::Invalid(path, err) => {
+                write!(f, "invalid fragment {}: {err}", path.display())
+            }
+            IncludeError::NotAFragment(path) => {
+                write!(f, "{} is not a node or an array of nodes", path.display())
+            }
+            IncludeError::OutsideTemplateDir(path) => {
+                write!(f, "{} is outside the template directory", path.display())
+            }
+            IncludeError::Cycle(chain) => {
+                let chain: Vec<_> = chain
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                write!(f, "includes form a cycle: {}", chain.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for IncludeError {}
+
+/// Replaces each `{"include": "<file>"}` in a group's children with the contents
+/// of the file, which is either a single node or an array of nodes.
+///
+/// The files are found relative to `template_dir` and may include others in
+/// turn, but not themselves.
+pub fn resolve_includes(v: &Value, template_dir: &Path) -> Result<Value, IncludeError> {
+    let dir = template_dir
+        .canonicalize()
+        .map_err(|err| IncludeError::Io(template_dir.to_owned(), err))?;
+    let mut v = v.clone();
+    expand(&mut v, &dir, &mut Vec::new())?;
+    Ok(v)
+}
+
+fn expand(v: &mut Value, dir: &Path, stack: &mut Vec<PathBuf>) -> Result<(), IncludeError> {
+    let Some(children) = v.get_mut("children").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+    let mut expanded = Vec::with_capacity(children.len());
+    for mut child in children.drain(..) {
+        match child.get("include").and_then(Value::as_str) {
+            Some(include) => {
+                let path = fragment_path(dir, include)?;
+                if let Some(first) = stack.iter().position(|p| *p == path) {
+                    let mut chain = stack[first..].to_vec();
+                    chain.push(path);
+                    return Err(IncludeError::Cycle(chain));
+                }
+                let fragment = read_fragment(&path)?;
+                stack.push(path);
+                for mut node in fragment {
+                    expand(&mut node, dir, stack)?;
+                    expanded.push(node);
+                }
+                stack.pop();
+            }
+            None => {
+                expand(&mut child, dir, stack)?;
+                expanded.push(child);
+            }
+        }
+    }
+    *children = expanded;
+    Ok(())
+}
+
+fn fragment_path(dir: &Path, include: &str) -> Result<PathBuf, IncludeError> {
+    let path = dir.join(include);
+    let path = path
+        .canonicalize()
+        .map_err(|err| IncludeError::Io(path, err))?;
+    if !path.starts_with(dir) {
+        return Err(IncludeError::OutsideTemplateDir(path));
+    }
+    Ok(path)
+}
+
+fn read_fragment(path: &Path) -> Result<Vec<Value>, IncludeError> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| IncludeError::Io(path.to_owned(), err))?;
+    match serde_json::from_str(&contents) {
+        Ok(Value::Array(nodes)) if nodes.iter().all(Value::is_object) => Ok(nodes),
+        Ok(node @ Value::Object(_)) => Ok(vec![node]),
+        Ok(_) => Err(IncludeError::NotAFragment(path.to_owned())),
+        Err(err) => Err(IncludeError::Invalid(path.to_owned(), err)),
+    }
+}
+
+/// Parses a `NAME=VALUE` pair, e.g. from the command line.
+pub fn parse_variable(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got {s}"))?;
+    if !is_name(name) {
+        return Err(format!("invalid placeholder name {name}"));
+    }
+    Ok((name.to_owned(), value.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::fixtures::TempDir;
+
+    use super::*;
+
+    fn variables(pairs: &[(&str, &str)]) -> Variables {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn dataset_and_attribute_values_are_substituted() {
+        let v = json!({
+            "name": "entry",
+            "attributes": [{"name": "proposal", "values": "$PROPOSAL$"}],
+            "children": [
+                {"module": "dataset", "config": {"name": "title", "dtype": "string", "values": "Run: $TITLE$"}}
+            ]
+        });
+
+        let result = substitute(
+            &v,
+            &variables(&[("TITLE", "Spin echo"), ("PROPOSAL", "123")]),
+        )
+        .unwrap();
+
+        assert_eq!(result["attributes"][0]["values"], "123");
+        assert_eq!(result["children"][0]["config"]["values"], "Run: Spin echo");
+    }
+
+    #[test]
+    fn other_strings_are_left_alone() {
+        let v = json!({"name": "$TITLE$", "config": {"topic": "$TITLE$"}});
+
+        assert_eq!(substitute(&v, &Variables::new()), Ok(v));
+    }
+
+    #[test]
+    fn all_unresolved_placeholders_are_reported() {
+        let v = json!([
+            {"values": "$B$ and $A$"},
+            {"values": ["$A$", "$C$"]}
+        ]);
+
+        let result = substitute(&v, &variables(&[("C", "c")]));
+
+        assert_eq!(
+            result,
+            Err(UnresolvedPlaceholders(vec!["A".to_owned(), "B".to_owned()]))
+        );
+    }
+
+    #[test]
+    fn lone_dollars_are_not_placeholders() {
+        let v = json!({"values": "costs $5 or $ 6$"});
+
+        assert_eq!(substitute(&v, &Variables::new()), Ok(v));
+    }
+
+    #[test]
+    fn numbers_between_dollars_are_not_placeholders() {
+        let v = json!({"values": "costs $5$ and $6$"});
+
+        assert_eq!(substitute(&v, &Variables::new()), Ok(v));
+    }
+
+    #[test]
+    fn substitute_in_a_single_string() {
+        let variables = Variables::from([("RUN".to_owned(), "42".to_owned())]);
+
+        assert_eq!(
+            substitute_string("run_$RUN$.nxs", &variables),
+            Ok("run_42.nxs".to_owned())
+        );
+        assert_eq!(
+            substitute_string("$DATE$/run_$RUN$.nxs", &variables),
+            Err(UnresolvedPlaceholders(vec!["DATE".to_owned()]))
+        );
+    }
+
+    fn template_dir(name: &str, files: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new(&format!("templates_{name}"));
+        for (name, contents) in files {
+            std::fs::write(dir.join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn included_nodes_are_merged_into_the_children() {
+        let dir = template_dir(
+            "merged",
+            &[
+                (
+                    "choppers.json",
+                    r#"[{"name": "chopper_1"}, {"name": "chopper_2"}]"#,
+                ),
+                (
+                    "detector.json",
+                    r#"{"name": "detector", "children": [{"include": "choppers.json"}]}"#,
+                ),
+            ],
+        );
+        let v = json!({"children": [
+            {"name": "sample"},
+            {"include": "detector.json"},
+            {"name": "source"}
+        ]});
+
+        let result = resolve_includes(&v, &dir).unwrap();
+
+        assert_eq!(
+            result,
+            json!({"children": [
+                {"name": "sample"},
+                {"name": "detector", "children": [{"name": "chopper_1"}, {"name": "chopper_2"}]},
+                {"name": "source"}
+            ]})
+        );
+    }
+
+    #[test]
+    fn a_fragment_can_be_included_more_than_once() {
+        let dir = template_dir("twice", &[("motor.json", r#"{"name": "motor"}"#)]);
+        let v = json!({"children": [
+            {"name": "a", "children": [{"include": "motor.json"}]},
+            {"name": "b", "children": [{"include": "motor.json"}]}
+        ]});
+
+        assert!(resolve_includes(&v, &dir).is_ok());
+    }
+
+    #[test]
+    fn include_cycles_are_an_error() {
+        let dir = template_dir(
+            "cycle",
+            &[
+                (
+                    "a.json",
+                    r#"{"name": "a", "children": [{"include": "b.json"}]}"#,
+                ),
+                (
+                    "b.json",
+                    r#"{"name": "b", "children": [{"include": "a.json"}]}"#,
+                ),
+            ],
+        );
+        let v = json!({"children": [{"include": "a.json"}]});
+
+        let result = resolve_includes(&v, &dir);
+
+        let Err(IncludeError::Cycle(chain)) = result else {
+            panic!("expected a cycle, got {result:?}");
+        };
+        let names: Vec<_> = chain.iter().map(|p| p.file_name().unwrap()).collect();
+        assert_eq!(names, ["a.json", "b.json", "a.json"]);
+    }
+
+    #[test]
+    fn includes_outside_the_template_dir_are_refused() {
+        let dir = template_dir("outside", &[]);
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        std::fs::write(dir.join("outside.json"), "{}").unwrap();
+        let v = json!({"children": [{"include": "../outside.json"}]});
+
+        let result = resolve_includes(&v, &dir.join("sub"));
+
+        assert!(matches!(result, Err(IncludeError::OutsideTemplateDir(_))));
+    }
+
+    #[test]
+    fn missing_fragment_is_an_error() {
+        let dir = template_dir("missing", &[]);
+        let v = json!({"children": [{"include": "missing.json"}]});
+
+        assert!(matches!(
+            resolve_includes(&v, &dir),
+            Err(IncludeError::Io(_, _))
+        ));
+    }
+
+    #[test]
+    fn parse_name_value_pair() {
+        assert_eq!(
+            parse_variable("TITLE=a=b"),
+            Ok(("TITLE".to_owned(), "a=b".to_owned()))
+        );
+        assert!(parse_variable("TITLE").is_err());
+        assert!(parse_variable("A B=c").is_err());
+    }
+}
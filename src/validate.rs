@@ -0,0 +1,450 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::json::ModuleSettings;
+use crate::nexus::{Attribute, Group, Link, Module, NexusNode, NexusStructure};
+use crate::writers::f144;
+
+/// Modules of the ECDC file writer which are recognised but not written, so the
+/// file is still written without them.
+const UNWRITTEN_MODULES: &[&str] = &[
+    "ADAr", "ad00", "al00", "da00", "ep00", "ep01", "ev42", "f142", "hs00", "hs01", "json", "mdat",
+    "ns10", "se00", "senv", "tdct",
+];
+
+/// Something in a nexus_structure which would stop the file being written as intended.
+///
+/// Anything which can't be parsed, e.g. a missing config field or values which don't
+/// match the dtype, is already an error from [`NexusStructure`]'s deserialisation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Problem {
+    /// Where in the file the problem is, e.g. "/entry/instrument".
+    pub path: String,
+    pub kind: ProblemKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProblemKind {
+    /// The top level must have children, e.g. the NXentry.
+    InvalidTopLevel,
+    UnknownModule(String),
+    /// A real module which this writer skips; only a warning.
+    UnwrittenModule(String),
+    UnsupportedDtype(String),
+    DuplicateName(String),
+    MissingLinkTarget(String),
+    /// The same schema, topic and source is written in more than one place.
+    DuplicateSource {
+        schema: String,
+        topic: String,
+        source: String,
+        first: String,
+    },
+}
+
+impl std::fmt::Display for ProblemKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProblemKind::InvalidTopLevel => write!(f, "top level must have children"),
+            ProblemKind::UnknownModule(module) => write!(f, "unknown module {module}"),
+            ProblemKind::UnwrittenModule(module) => {
+                write!(
+                    f,
+                    "{module} modules aren't written yet, so it will be skipped"
+                )
+            }
+            ProblemKind::UnsupportedDtype(dtype) => write!(f, "unsupported dtype {dtype}"),
+            ProblemKind::DuplicateName(name) => write!(f, "{name} appears more than once"),
+            ProblemKind::MissingLinkTarget(target) => {
+                write!(f, "link target {target} does not exist")
+            }
+            ProblemKind::DuplicateSource {
+                schema,
+                topic,
+                source,
+                first,
+            } => write!(
+                f,
+                "{schema} {source} on {topic} is already written to {first}"
+            ),
+        }
+    }
+}
+
+impl ProblemKind {
+    /// Whether the file can still be written, just without something in it.
+    pub fn is_warning(&self) -> bool {
+        matches!(self, ProblemKind::UnwrittenModule(_))
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// A link, checked once everything it could point to is known.
+struct LinkTarget {
+    path: String,
+    target: String,
+}
+
+#[derive(Default)]
+struct Validator {
+    problems: Vec<Problem>,
+    /// Everything that will exist in the file.
+    paths: HashSet<String>,
+    /// Groups with streaming modules, which create datasets when the job starts.
+    streaming_groups: HashSet<String>,
+    links: Vec<LinkTarget>,
+    sources: HashMap<(String, String, String), String>,
+}
+
+/// Checks a nexus_structure without creating a file and returns every problem found.
+pub fn validate(structure: &NexusStructure) -> Vec<Problem> {
+    let mut validator = Validator::default();
+    if structure.children.is_empty() {
+        validator.report("/", ProblemKind::InvalidTopLevel);
+    } else {
+        validator.attributes("/", &structure.attributes);
+        validator.children("", &structure.children);
+    }
+    validator.check_links();
+    validator.problems
+}
+
+fn join(parent: &str, name: &str) -> String {
+    format!("{parent}/{name}")
+}
+
+impl Validator {
+    fn report(&mut self, path: &str, kind: ProblemKind) {
+        // The top level's children have an empty parent.
+        let path = if path.is_empty() { "/" } else { path };
+        self.problems.push(Problem {
+            path: path.to_owned(),
+            kind,
+        });
+    }
+
+    fn group(&mut self, parent: &str, group: &Group) {
+        let path = join(parent, &group.name);
+        self.paths.insert(path.clone());
+        self.attributes(&path, &group.attributes);
+        self.children(&path, &group.children);
+    }
+
+    fn children(&mut self, path: &str, children: &[NexusNode]) {
+        let mut names = HashSet::new();
+        for child in children {
+            // Groups, datasets and links all create something with a name in the group.
+            let child_name = match child {
+                NexusNode::Group(group) => Some(&group.name),
+                NexusNode::Dataset(dataset) => Some(&dataset.name),
+                NexusNode::Link(link) => Some(&link.name),
+                NexusNode::Module(_) => None,
+            };
+            if let Some(child_name) = child_name {
+                if !names.insert(child_name) {
+                    self.report(path, ProblemKind::DuplicateName(child_name.clone()));
+                }
+            }
+            self.child(path, child);
+        }
+    }
+
+    fn attributes(&mut self, path: &str, attributes: &[Attribute]) {
+        let mut names = HashSet::new();
+        for attribute in attributes {
+            if !names.insert(&attribute.name) {
+                self.report(
+                    path,
+                    ProblemKind::DuplicateName(format!("attribute {}", attribute.name)),
+                );
+            }
+        }
+    }
+
+    fn child(&mut self, parent: &str, node: &NexusNode) {
+        match node {
+            NexusNode::Group(group) => self.group(parent, group),
+            NexusNode::Dataset(dataset) => {
+                let path = join(parent, &dataset.name);
+                self.attributes(&path, &dataset.attributes);
+                self.paths.insert(path);
+            }
+            NexusNode::Link(link) => self.link(parent, link),
+            NexusNode::Module(module) => self.module(parent, module),
+        }
+    }
+
+    fn link(&mut self, parent: &str, link: &Link) {
+        let path = join(parent, &link.name);
+        self.paths.insert(path.clone());
+        let target = if link.source.starts_with('/') {
+            link.source.trim_end_matches('/').to_owned()
+        } else {
+            join(parent, &link.source)
+        };
+        self.links.push(LinkTarget { path, target });
+    }
+
+    fn module(&mut self, parent: &str, module: &Module) {
+        let Some(settings) = &module.settings else {
+            let kind = if UNWRITTEN_MODULES.contains(&module.module.as_str()) {
+                ProblemKind::UnwrittenModule(module.module.clone())
+            } else {
+                ProblemKind::UnknownModule(module.module.clone())
+            };
+            self.report(parent, kind);
+            return;
+        };
+        self.streaming_groups.insert(parent.to_owned());
+        if let ModuleSettings::F144(settings) = settings {
+            if !f144::DTYPES.contains(&settings.dtype.as_str()) {
+                self.report(
+                    parent,
+                    ProblemKind::UnsupportedDtype(settings.dtype.clone()),
+                );
+            }
+        }
+        let key = settings.source_key();
+        let key = (key.schema, settings.topic().to_owned(), key.source);
+        if let Some(first) = self.sources.get(&key) {
+            let kind = ProblemKind::DuplicateSource {
+                schema: key.0,
+                topic: key.1,
+                source: key.2,
+                first: first.clone(),
+            };
+            self.report(parent, kind);
+        } else {
+            self.sources.insert(key, parent.to_owned());
+        }
+    }
+
+    fn check_links(&mut self) {
+        for link in std::mem::take(&mut self.links) {
+            // The datasets of streaming modules aren't known until the writers are created.
+            let in_streaming_group = link
+                .target
+                .rsplit_once('/')
+                .is_some_and(|(group, _)| self.streaming_groups.contains(group));
+            if !self.paths.contains(&link.target) && !in_streaming_group {
+                self.report(&link.path, ProblemKind::MissingLinkTarget(link.target));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    fn entry(children: Value) -> Value {
+        serde_json::json!({
+            "children": [{
+                "name": "entry",
+                "type": "group",
+                "attributes": [{"name": "NX_class", "dtype": "string", "values": "NXentry"}],
+                "children": children
+            }]
+        })
+    }
+
+    fn check(v: &Value) -> Vec<Problem> {
+        validate(&serde_json::from_value(v.clone()).unwrap())
+    }
+
+    fn kinds(v: &Value) -> Vec<ProblemKind> {
+        check(v).into_iter().map(|p| p.kind).collect()
+    }
+
+    #[test]
+    fn example_structure_only_has_warnings() {
+        let v: Value = serde_json::from_str(include_str!("../nxs.json")).unwrap();
+
+        let problems = kinds(&v);
+
+        assert!(!problems.is_empty());
+        assert!(problems.iter().all(ProblemKind::is_warning));
+    }
+
+    #[test]
+    fn valid_structure_has_no_problems() {
+        let v = entry(serde_json::json!([
+            {"module": "dataset", "config": {"name": "title", "dtype": "string", "values": "x"}},
+            {"module": "dataset", "config": {"name": "m", "dtype": "int32", "values": [[1, 2], [3, 4]]}},
+            {"module": "link", "config": {"name": "title_link", "source": "/entry/title"}},
+            {"module": "f144", "config": {"source": "s", "topic": "t", "dtype": "double"}},
+        ]));
+
+        assert_eq!(check(&v), []);
+    }
+
+    #[test]
+    fn top_level_must_have_children() {
+        let v = serde_json::json!({"children": []});
+
+        assert_eq!(kinds(&v), [ProblemKind::InvalidTopLevel]);
+    }
+
+    #[test]
+    fn top_level_can_have_several_entries_and_datasets() {
+        let v = serde_json::json!({
+            "attributes": [{"name": "creator", "values": "filewriter"}],
+            "children": [
+                {"name": "entry", "type": "group", "attributes": [], "children": []},
+                {"name": "entry_1", "type": "group", "attributes": [], "children": []},
+                {"module": "dataset", "config": {"name": "file_name", "dtype": "string", "values": "a.nxs"}},
+                {"module": "link", "config": {"name": "raw", "source": "/entry"}}
+            ]
+        });
+
+        assert_eq!(check(&v), []);
+    }
+
+    #[test]
+    fn top_level_names_must_be_unique() {
+        let v = serde_json::json!({"children": [
+            {"name": "entry", "type": "group", "attributes": [], "children": []},
+            {"name": "entry", "type": "group", "attributes": [], "children": []}
+        ]});
+
+        assert_eq!(kinds(&v), [ProblemKind::DuplicateName("entry".to_owned())]);
+    }
+
+    #[test]
+    fn groups_do_not_need_attributes() {
+        let v = entry(serde_json::json!([{"name": "sample", "type": "group"}]));
+
+        assert_eq!(check(&v), []);
+    }
+
+    #[test]
+    fn attributes_can_be_a_map() {
+        let v = serde_json::json!({"children": [{
+            "name": "entry",
+            "type": "group",
+            "attributes": {"NX_class": "NXentry", "title": "x"},
+            "children": [{"name": "sample", "type": "group"}]
+        }]});
+
+        assert_eq!(check(&v), []);
+    }
+
+    #[test]
+    fn duplicate_attributes_are_reported() {
+        let v = entry(
+            serde_json::json!([{"name": "sample", "type": "group", "attributes": [
+                {"name": "NX_class", "values": "NXsample"},
+                {"name": "NX_class", "values": "NXsample"}
+            ]}]),
+        );
+
+        assert_eq!(
+            kinds(&v),
+            [ProblemKind::DuplicateName("attribute NX_class".to_owned())]
+        );
+    }
+
+    #[test]
+    fn unknown_module_is_reported() {
+        let v = entry(serde_json::json!([{"module": "xyz1", "config": {}}]));
+
+        assert_eq!(
+            check(&v),
+            [Problem {
+                path: "/entry".to_owned(),
+                kind: ProblemKind::UnknownModule("xyz1".to_owned())
+            }]
+        );
+    }
+
+    #[test]
+    fn modules_which_are_not_written_are_warnings() {
+        let v = entry(serde_json::json!([{"module": "tdct", "config": {}}]));
+
+        let problems = kinds(&v);
+
+        assert_eq!(problems, [ProblemKind::UnwrittenModule("tdct".to_owned())]);
+        assert!(problems[0].is_warning());
+        assert!(!ProblemKind::UnknownModule("xyz1".to_owned()).is_warning());
+    }
+
+    #[test]
+    fn bad_f144_dtype_is_reported() {
+        let v = entry(serde_json::json!([
+            {"module": "f144", "config": {"source": "s", "topic": "t", "dtype": "string"}},
+        ]));
+
+        assert_eq!(
+            kinds(&v),
+            [ProblemKind::UnsupportedDtype("string".to_owned())]
+        );
+    }
+
+    #[test]
+    fn duplicate_names_in_a_group_are_reported() {
+        let v = entry(serde_json::json!([
+            {"module": "dataset", "config": {"name": "a", "dtype": "string", "values": "x"}},
+            {"name": "a", "type": "group", "attributes": [], "children": []},
+        ]));
+
+        assert_eq!(kinds(&v), [ProblemKind::DuplicateName("a".to_owned())]);
+    }
+
+    #[test]
+    fn missing_link_target_is_reported() {
+        let v = entry(serde_json::json!([
+            {"module": "link", "config": {"name": "l", "source": "/entry/missing"}},
+        ]));
+
+        assert_eq!(
+            check(&v),
+            [Problem {
+                path: "/entry/l".to_owned(),
+                kind: ProblemKind::MissingLinkTarget("/entry/missing".to_owned())
+            }]
+        );
+    }
+
+    #[test]
+    fn links_to_streamed_datasets_are_allowed() {
+        let v = entry(serde_json::json!([
+            {"name": "log", "type": "group", "attributes": [], "children": [
+                {"module": "f144", "config": {"source": "s", "topic": "t", "dtype": "double"}}
+            ]},
+            {"module": "link", "config": {"name": "l", "source": "/entry/log/value"}},
+        ]));
+
+        assert_eq!(check(&v), []);
+    }
+
+    #[test]
+    fn source_used_twice_is_reported() {
+        let v = entry(serde_json::json!([
+            {"name": "a", "type": "group", "attributes": [], "children": [
+                {"module": "ev44", "config": {"source": "s", "topic": "t"}}
+            ]},
+            {"name": "b", "type": "group", "attributes": [], "children": [
+                {"module": "ev44", "config": {"source": "s", "topic": "t"}}
+            ]},
+        ]));
+
+        assert_eq!(
+            check(&v),
+            [Problem {
+                path: "/entry/b".to_owned(),
+                kind: ProblemKind::DuplicateSource {
+                    schema: "ev44".to_owned(),
+                    topic: "t".to_owned(),
+                    source: "s".to_owned(),
+                    first: "/entry/a".to_owned(),
+                }
+            }]
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::{Number, Value};
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+#[derive(Debug)]
+pub enum ValuesError {
+    Io(PathBuf, std::io::Error),
+    OutsideTemplateDir(PathBuf),
+    Invalid(PathBuf, String),
+    /// The file's values can't be written with the dataset's dtype.
+    UnsupportedDtype(PathBuf, String),
+    ShapeMismatch {
+        path: PathBuf,
+        shape: Vec<usize>,
+        count: usize,
+    },
+}
+
+impl std::fmt::Display for ValuesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValuesError::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            ValuesError::OutsideTemplateDir(path) => {
+                write!(f, "{} is outside the template directory", path.display())
+            }
+            ValuesError::Invalid(path, reason) => {
+                write!(f, "invalid values in {}: {reason}", path.display())
+            }
+            ValuesError::UnsupportedDtype(path, dtype) => write!(
+                f,
+                "values in {} can't be written as {dtype}",
+                path.display()
+            ),
+            ValuesError::ShapeMismatch { path, shape, count } => write!(
+                f,
+                "{} has {count} values which don't fit the shape {shape:?}",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValuesError {}
+
+/// Replaces the `file` of each dataset module with the `values` read from it, so
+/// large arrays don't have to be written inline in the template.
+///
+/// The files are found relative to `template_dir` and are either NumPy `.npy`
+/// files or CSV. The values take the file's shape unless the config declares a
+/// `shape`, in which case they must fit it.
+pub fn load_external_values(v: &Value, template_dir: &Path) -> Result<Value, ValuesError> {
+    let dir = template_dir
+        .canonicalize()
+        .map_err(|err| ValuesError::Io(template_dir.to_owned(), err))?;
+    let mut v = v.clone();
+    load(&mut v, &dir)?;
+    Ok(v)
+}
+
+fn load(v: &mut Value, dir: &Path) -> Result<(), ValuesError> {
+    if v["module"] == "dataset" {
+        if let Some(config) = v.get_mut("config").and_then(Value::as_object_mut) {
+            if let Some(file) = config.get("file").and_then(Value::as_str) {
+                let dtype = config.get("dtype").and_then(Value::as_str).unwrap_or("");
+                let shape = match config.get("shape") {
+                    Some(shape) => Some(
+                        serde_json::from_value::<Vec<usize>>(shape.clone()).map_err(|err| {
+                            ValuesError::Invalid(PathBuf::from(file), format!("shape: {err}"))
+                        })?,
+                    ),
+                    None => None,
+                };
+                let values = read_values(&dir.join(file), dir, dtype, shape)?;
+                config.remove("file");
+                config.insert("values".to_owned(), values);
+            }
+        }
+    }
+    if let Some(children) = v.get_mut("children").and_then(Value::as_array_mut) {
+        for child in children {
+            load(child, dir)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_values(
+    path: &Path,
+    dir: &Path,
+    dtype: &str,
+    declared_shape: Option<Vec<usize>>,
+) -> Result<Value, ValuesError> {
+    let path = path
+        .canonicalize()
+        .map_err(|err| ValuesError::Io(path.to_owned(), err))?;
+    if !path.starts_with(dir) {
+        return Err(ValuesError::OutsideTemplateDir(path));
+    }
+    let integers = match dtype {
+        "int32" | "int64" => true,
+        "double" | "float" => false,
+        _ => return Err(ValuesError::UnsupportedDtype(path, dtype.to_owned())),
+    };
+    let bytes = std::fs::read(&path).map_err(|err| ValuesError::Io(path.clone(), err))?;
+    let parsed = if bytes.starts_with(NPY_MAGIC) {
+        parse_npy(&bytes)
+    } else {
+        std::str::from_utf8(&bytes)
+            .map_err(|err| err.to_string())
+            .and_then(|text| parse_csv(text, integers))
+    };
+    let (shape, values) = parsed.map_err(|reason| ValuesError::Invalid(path.clone(), reason))?;
+    if integers && values.iter().any(|value| value.as_i64().is_none()) {
+        return Err(ValuesError::UnsupportedDtype(path, dtype.to_owned()));
+    }
+    let shape = declared_shape.unwrap_or(shape);
+    if shape.iter().product::<usize>() != values.len() {
+        return Err(ValuesError::ShapeMismatch {
+            path,
+            shape,
+            count: values.len(),
+        });
+    }
+    Ok(nest(&shape, &values))
+}
+
+/// Builds the nested array the inline `values` would have had.
+pub(crate) fn nest(shape: &[usize], values: &[Number]) -> Value {
+    match shape.split_first() {
+        None => Value::Number(values[0].clone()),
+        Some((_, rest)) => {
+            let stride = rest.iter().product::<usize>().max(1);
+            Value::Array(
+                values
+                    .chunks(stride)
+                    .map(|chunk| nest(rest, chunk))
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Reads the shape and values of a NumPy `.npy` file holding ints or floats.
+pub fn parse_npy(bytes: &[u8]) -> Result<(Vec<usize>, Vec<Number>), String> {
+    let truncated = || "truncated".to_owned();
+    let major = *bytes.get(NPY_MAGIC.len()).ok_or_else(truncated)?;
+    let (header_len, header_start) = match major {
+        1 => {
+            let len = bytes.get(8..10).ok_or_else(truncated)?;
+            (u16::from_le_bytes([len[0], len[1]]) as usize, 10)
+        }
+        2 | 3 => {
+            let len = bytes.get(8..12).ok_or_else(truncated)?;
+            (
+                u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize,
+                12,
+            )
+        }
+        _ => return Err(format!("unsupported npy version {major}")),
+    };
+    let data_start = header_len.checked_add(header_start).ok_or_else(truncated)?;
+    let header = bytes.get(header_start..data_start).ok_or_else(truncated)?;
+    let header = std::str::from_utf8(header).map_err(|err| err.to_string())?;
+    let data = &bytes[data_start..];
+
+    let descr = header_value(header, "descr")?.trim_matches(|c| c == '\'' || c == '"');
+    if header_value(header, "fortran_order")? != "False" {
+        return Err("fortran order is not supported".to_owned());
+    }
+    let shape = header_value(header, "shape")?
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>().map_err(|err| err.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (little_endian, kind, size) = match descr.as_bytes() {
+        [order @ (b'<' | b'>' | b'|' | b'='), kind, size @ ..] => (
+            *order != b'>',
+            *kind,
+            std::str::from_utf8(size)
+                .ok()
+                .and_then(|size| size.parse::<usize>().ok())
+                .ok_or_else(|| format!("unsupported dtype {descr}"))?,
+        ),
+        _ => return Err(format!("unsupported dtype {descr}")),
+    };
+    if !(1..=8).contains(&size) {
+        return Err(format!("unsupported dtype {descr}"));
+    }
+    // The shape comes from the file, so could be anything.
+    let data_len = shape
+        .iter()
+        .try_fold(size, |len, dim| len.checked_mul(*dim))
+        .ok_or_else(|| format!("shape {shape:?} is too large"))?;
+    if data.len() < data_len {
+        return Err(truncated());
+    }
+    let values = data[..data_len]
+        .chunks(size)
+        .map(|chunk| {
+            let mut raw = [0; 8];
+            if little_endian {
+                raw[..size].copy_from_slice(chunk);
+            } else {
+                raw[8 - size..].copy_from_slice(chunk);
+            }
+            let bits = if little_endian {
+                u64::from_le_bytes(raw)
+            } else {
+                u64::from_be_bytes(raw)
+            };
+            to_number(kind, size, bits).ok_or_else(|| format!("unsupported value in {descr}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((shape, values))
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, String> {
+    let missing = || format!("header has no {key}");
+    let start = header.find(&format!("'{key}'")).ok_or_else(missing)? + key.len() + 2;
+    let rest = header[start..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(missing)?;
+    let rest = rest.trim_start();
+    // The shape is a tuple, so contains commas.
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|end| end + 1)
+    } else {
+        rest.find([',', '}'])
+    };
+    Ok(rest[..end.ok_or_else(missing)?].trim())
+}
+
+fn to_number(kind: u8, size: usize, bits: u64) -> Option<Number> {
+    match (kind, size) {
+        (b'f', 4) => Number::from_f64(f32::from_bits(bits as u32) as f64),
+        (b'f', 8) => Number::from_f64(f64::from_bits(bits)),
+        (b'i', 1..=8) => {
+            // Sign extend from the value's size.
+            let shift = 64 - 8 * size as u32;
+            Some(Number::from(((bits << shift) as i64) >> shift))
+        }
+        (b'u', 1..=8) => i64::try_from(bits).ok().map(Number::from),
+        _ => None,
+    }
+}
+
+/// Creates a NumPy `.npy` file holding little-endian doubles.
+pub fn npy_f64(shape: &[usize], values: &[f64]) -> Vec<u8> {
+    let data = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    npy_bytes("<f8", shape, data)
+}
+
+/// Creates a NumPy `.npy` file holding little-endian 64-bit ints.
+pub fn npy_i64(shape: &[usize], values: &[i64]) -> Vec<u8> {
+    let data = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+    npy_bytes("<i8", shape, data)
+}
+
+fn npy_bytes(descr: &str, shape: &[usize], data: Vec<u8>) -> Vec<u8> {
+    let dims: Vec<_> = shape.iter().map(usize::to_string).collect();
+    // A one element tuple needs a trailing comma.
+    let shape = match dims.as_slice() {
+        [dim] => format!("({dim},)"),
+        _ => format!("({})", dims.join(", ")),
+    };
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    // The data starts on a 64 byte boundary, after the header's newline.
+    let prefix = NPY_MAGIC.len() + 4;
+    let padding = 63 - (prefix + header.len()) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = NPY_MAGIC.to_vec();
+    bytes.extend([1, 0]);
+    bytes.extend((header.len() as u16).to_le_bytes());
+    bytes.extend(header.as_bytes());
+    bytes.extend(data);
+    bytes
+}
+
+/// Reads comma separated values, one row per line, skipping blank lines and
+/// `#` comments.
+///
+/// A single column is read as a 1D array, anything else as 2D.
+pub fn parse_csv(text: &str, integers: bool) -> Result<(Vec<usize>, Vec<Number>), String> {
+    let mut values = Vec::new();
+    let mut rows = 0;
+    let mut columns = None;
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut row_length = 0;
+        for field in line.split(',') {
+            let field = field.trim();
+            let value = if integers {
+                field.parse::<i64>().ok().map(Number::from)
+            } else {
+                field.parse::<f64>().ok().and_then(Number::from_f64)
+            };
+            let value = value
+                .ok_or_else(|| format!("line {}: invalid value {field:?}", line_number + 1))?;
+            values.push(value);
+            row_length += 1;
+        }
+        match columns {
+            None => columns = Some(row_length),
+            Some(columns) if columns != row_length => {
+                return Err(format!(
+                    "line {}: expected {columns} values, got {row_length}",
+                    line_number + 1
+                ));
+            }
+            Some(_) => {}
+        }
+        rows += 1;
+    }
+    match columns {
+        Some(1) => Ok((vec![rows], values)),
+        Some(columns) => Ok((vec![rows, columns], values)),
+        None => Err("no values".to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::fixtures::TempDir;
+
+    use super::*;
+
+    fn npy(descr: &str, shape: &str, data: &[u8]) -> Vec<u8> {
+        let header =
+            format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}\n");
+        let mut bytes = NPY_MAGIC.to_vec();
+        bytes.extend([1, 0]);
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn parse_npy_doubles() {
+        let data: Vec<u8> = [1.5f64, -2.0, 3.25, 4.0, 5.0, 6.0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+
+        let (shape, values) = parse_npy(&npy("<f8", "(2, 3)", &data)).unwrap();
+
+        assert_eq!(shape, vec![2, 3]);
+        let values: Vec<f64> = values.iter().map(|v| v.as_f64().unwrap()).collect();
+        assert_eq!(values, vec![1.5, -2.0, 3.25, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn parse_npy_big_endian_ints() {
+        let data: Vec<u8> = [-1i32, 7].iter().flat_map(|v| v.to_be_bytes()).collect();
+
+        let (shape, values) = parse_npy(&npy(">i4", "(2,)", &data)).unwrap();
+
+        assert_eq!(shape, vec![2]);
+        assert_eq!(values, vec![Number::from(-1), Number::from(7)]);
+    }
+
+    #[test]
+    fn written_npy_can_be_read_back() {
+        let bytes = npy_i64(&[3, 1], &[1, -2, 3]);
+
+        let (shape, values) = parse_npy(&bytes).unwrap();
+
+        assert_eq!(bytes.len() % 64, 3 * 8);
+        assert_eq!(shape, vec![3, 1]);
+        assert_eq!(
+            values,
+            vec![Number::from(1), Number::from(-2), Number::from(3)]
+        );
+    }
+
+    #[test]
+    fn written_npy_can_be_one_dimensional() {
+        let (shape, values) = parse_npy(&npy_f64(&[2], &[0.5, 1.5])).unwrap();
+
+        assert_eq!(shape, vec![2]);
+        assert_eq!(values[1].as_f64(), Some(1.5));
+    }
+
+    #[test]
+    fn truncated_npy_is_an_error() {
+        let data = 1.0f64.to_le_bytes();
+
+        assert!(parse_npy(&npy("<f8", "(2,)", &data)).is_err());
+    }
+
+    #[test]
+    fn npy_shape_too_large_is_an_error() {
+        let shape = format!("({}, {})", usize::MAX, usize::MAX);
+
+        let result = parse_npy(&npy("<f8", &shape, &[]));
+
+        assert!(result.unwrap_err().contains("too large"));
+    }
+
+    #[test]
+    fn parse_csv_table() {
+        let text = "# x, y\n1.0, 2.0\n\n3.0,4.5\n";
+
+        let (shape, values) = parse_csv(text, false).unwrap();
+
+        assert_eq!(shape, vec![2, 2]);
+        let values: Vec<f64> = values.iter().map(|v| v.as_f64().unwrap()).collect();
+        assert_eq!(values, vec![1.0, 2.0, 3.0, 4.5]);
+    }
+
+    #[test]
+    fn csv_column_is_one_dimensional() {
+        let (shape, _) = parse_csv("1\n2\n3\n", true).unwrap();
+
+        assert_eq!(shape, vec![3]);
+    }
+
+    #[test]
+    fn ragged_csv_is_an_error() {
+        assert!(parse_csv("1,2\n3\n", true).is_err());
+        assert!(parse_csv("1.5\n", true).is_err());
+    }
+
+    #[test]
+    fn file_is_replaced_with_values_of_the_declared_shape() {
+        let dir = TempDir::new("values");
+        std::fs::write(dir.join("offsets.csv"), "1,2,3,4,5,6\n").unwrap();
+        let v = json!({"children": [{
+            "module": "dataset",
+            "config": {"name": "x_pixel_offset", "dtype": "int64", "shape": [3, 2], "file": "offsets.csv"}
+        }]});
+
+        let result = load_external_values(&v, &dir).unwrap();
+
+        let config = &result["children"][0]["config"];
+        assert_eq!(config["values"], json!([[1, 2], [3, 4], [5, 6]]));
+        assert!(config.get("file").is_none());
+    }
+
+    #[test]
+    fn values_must_fit_the_declared_shape() {
+        let dir = TempDir::new("values_shape");
+        std::fs::write(dir.join("offsets.csv"), "1.0\n2.0\n3.0\n").unwrap();
+        let v = json!({"module": "dataset", "config": {"name": "x", "dtype": "double", "shape": [2, 2], "file": "offsets.csv"}});
+
+        let result = load_external_values(&v, &dir);
+
+        assert!(matches!(
+            result,
+            Err(ValuesError::ShapeMismatch { count: 3, .. })
+        ));
+    }
+}
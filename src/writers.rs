@@ -0,0 +1,339 @@
+use std::time::{Duration, Instant};
+
+use hdf5::{Dataset, H5Type};
+
+use crate::ev44::Ev44Error;
+use crate::json::ModuleSettings;
+use crate::writers::ev44::Ev44Writer;
+use crate::writers::f144::F144Writer;
+
+pub mod ev44;
+pub mod f144;
+pub mod storage;
+
+#[derive(Debug)]
+pub enum WriteError {
+    InvalidMessage(flatbuffers::InvalidFlatbuffer),
+    InvalidEv44(Ev44Error),
+    Hdf5(hdf5::Error),
+}
+
+impl std::fmt::Display for WriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WriteError::InvalidMessage(err) => write!(f, "invalid message: {err}"),
+            WriteError::InvalidEv44(err) => write!(f, "invalid ev44 message: {err}"),
+            WriteError::Hdf5(err) => write!(f, "HDF5 error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for WriteError {}
+
+impl From<flatbuffers::InvalidFlatbuffer> for WriteError {
+    fn from(err: flatbuffers::InvalidFlatbuffer) -> Self {
+        WriteError::InvalidMessage(err)
+    }
+}
+
+impl From<Ev44Error> for WriteError {
+    fn from(err: Ev44Error) -> Self {
+        match err {
+            Ev44Error::InvalidFlatbuffer(err) => WriteError::InvalidMessage(err),
+            err => WriteError::InvalidEv44(err),
+        }
+    }
+}
+
+impl From<hdf5::Error> for WriteError {
+    fn from(err: hdf5::Error) -> Self {
+        WriteError::Hdf5(err)
+    }
+}
+
+/// Common interface for the modules which write streamed data into the file.
+///
+/// Writers are created on the job's thread and then handed over to its writer
+/// thread, hence `Send`.
+pub trait Writer: Send {
+    /// Writes a raw flatbuffer message, which may be buffered rather than written
+    /// to the file straight away.
+    fn write(&mut self, buf: &[u8]) -> Result<(), WriteError>;
+
+    /// Writes any buffered data to the file.
+    fn flush(&mut self) -> hdf5::Result<()>;
+
+    /// Flushes if the buffered data has hit one of the thresholds or it has been
+    /// sitting around too long.
+    ///
+    /// This should be called periodically even if no messages are arriving, so
+    /// readers of the file see the data promptly.
+    fn flush_if_due(&mut self, now: Instant) -> hdf5::Result<()>;
+
+    /// The path of each dataset the writer appends to, with how many values have
+    /// been written to the file so far, not counting anything still buffered.
+    fn lengths(&self) -> Vec<(String, usize)>;
+}
+
+/// Creates the writer for a streaming module in the group the module was found in.
+pub fn create_writer(
+    file: &hdf5::File,
+    module: &ModuleSettings,
+    policy: FlushPolicy,
+) -> hdf5::Result<Box<dyn Writer>> {
+    match module {
+        ModuleSettings::F144(settings) => {
+            let group = file.group(&settings.path)?;
+            let options = F144Writer::default_options().with_settings(&settings.storage);
+            let writer = F144Writer::create(
+                &group,
+                &settings.dtype,
+                settings.value_units.as_deref(),
+                &options,
+                policy,
+            )?;
+            Ok(Box::new(writer))
+        }
+        ModuleSettings::Ev44(settings) => {
+            let group = file.group(&settings.path)?;
+            let options = Ev44Writer::default_options().with_settings(&settings.storage);
+            Ok(Box::new(Ev44Writer::create(&group, &options, policy)?))
+        }
+    }
+}
+
+/// Thresholds at which a writer's buffered data gets written to the file.
+///
+/// Whichever threshold is hit first triggers the flush.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlushPolicy {
+    pub max_bytes: usize,
+    pub max_messages: usize,
+    pub max_interval: Duration,
+}
+
+impl Default for FlushPolicy {
+    fn default() -> Self {
+        FlushPolicy {
+            max_bytes: 16 * 1024 * 1024,
+            max_messages: 1000,
+            max_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Keeps track of how much has been buffered since the last flush.
+#[derive(Debug)]
+pub struct WriteBuffer {
+    policy: FlushPolicy,
+    bytes: usize,
+    messages: usize,
+    last_flush: Instant,
+}
+
+impl WriteBuffer {
+    pub fn new(policy: FlushPolicy, now: Instant) -> WriteBuffer {
+        WriteBuffer {
+            policy,
+            bytes: 0,
+            messages: 0,
+            last_flush: now,
+        }
+    }
+
+    pub fn policy(&self) -> &FlushPolicy {
+        &self.policy
+    }
+
+    pub fn record_message(&mut self, bytes: usize) {
+        self.bytes += bytes;
+        self.messages += 1;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages == 0
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        self.bytes >= self.policy.max_bytes
+            || self.messages >= self.policy.max_messages
+            || now.saturating_duration_since(self.last_flush) >= self.policy.max_interval
+    }
+
+    pub fn flushed(&mut self, now: Instant) {
+        self.bytes = 0;
+        self.messages = 0;
+        self.last_flush = now;
+    }
+}
+
+/// A one-dimensional extendible dataset with values waiting to be appended.
+///
+/// The pending values are written with a single resize and write when flushed.
+pub struct BufferedDataset<T: H5Type + Copy> {
+    dataset: Dataset,
+    pending: Vec<T>,
+    written: usize,
+}
+
+impl<T: H5Type + Copy> BufferedDataset<T> {
+    pub fn new(dataset: Dataset) -> BufferedDataset<T> {
+        BufferedDataset {
+            dataset,
+            pending: Vec::new(),
+            written: 0,
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.pending.push(value);
+    }
+
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.pending.extend_from_slice(values);
+    }
+
+    /// Writes the values straight to the file, after any pending values.
+    ///
+    /// Useful when the values are already big enough that buffering them would
+    /// just be an extra copy.
+    pub fn write_through(&mut self, values: &[T]) -> hdf5::Result<()> {
+        self.flush()?;
+        append_slice(&self.dataset, values, self.written)?;
+        self.written += values.len();
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> hdf5::Result<()> {
+        append_slice(&self.dataset, &self.pending, self.written)?;
+        self.written += self.pending.len();
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// The dataset's path in the file.
+    pub fn path(&self) -> String {
+        self.dataset.name()
+    }
+
+    /// The number of values which have been written to the file.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// The length of the dataset including any values not yet written.
+    pub fn len(&self) -> usize {
+        self.written + self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
+
+impl<T: H5Type + Copy> Drop for BufferedDataset<T> {
+    fn drop(&mut self) {
+        // Last chance to get the data into the file; writers should call flush
+        // when stopping so errors can be reported.
+        if self.has_pending() {
+            let _ = self.flush();
+        }
+    }
+}
+
+/// Appends values to the end of a one-dimensional extendible dataset.
+///
+/// `offset` is the current length of the dataset, which the caller tracks so we
+/// don't have to query HDF5 for the shape on every append.
+pub fn append_slice<T: H5Type>(ds: &Dataset, values: &[T], offset: usize) -> hdf5::Result<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let end = offset + values.len();
+    ds.resize(end)?;
+    ds.write_slice(values, offset..end)
+}
+
+/// Adds a string attribute, e.g. "units", to a dataset.
+pub fn write_string_attribute(ds: &Dataset, name: &str, value: &str) -> hdf5::Result<()> {
+    let value: hdf5::types::VarLenUnicode = value.parse().unwrap();
+    let attr = ds.new_attr::<hdf5::types::VarLenUnicode>().create(name)?;
+    attr.write_scalar(&value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> FlushPolicy {
+        FlushPolicy {
+            max_bytes: 100,
+            max_messages: 3,
+            max_interval: Duration::from_secs(2),
+        }
+    }
+
+    #[test]
+    fn empty_buffer_is_never_due() {
+        let start = Instant::now();
+        let buffer = WriteBuffer::new(policy(), start);
+
+        assert!(!buffer.is_due(start + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn due_when_bytes_threshold_reached() {
+        let start = Instant::now();
+        let mut buffer = WriteBuffer::new(policy(), start);
+
+        buffer.record_message(60);
+        assert!(!buffer.is_due(start));
+        buffer.record_message(40);
+
+        assert!(buffer.is_due(start));
+    }
+
+    #[test]
+    fn due_when_message_threshold_reached() {
+        let start = Instant::now();
+        let mut buffer = WriteBuffer::new(policy(), start);
+
+        buffer.record_message(1);
+        buffer.record_message(1);
+        assert!(!buffer.is_due(start));
+        buffer.record_message(1);
+
+        assert!(buffer.is_due(start));
+    }
+
+    #[test]
+    fn due_when_interval_elapsed() {
+        let start = Instant::now();
+        let mut buffer = WriteBuffer::new(policy(), start);
+
+        buffer.record_message(1);
+
+        assert!(!buffer.is_due(start + Duration::from_secs(1)));
+        assert!(buffer.is_due(start + Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn flushing_resets_thresholds() {
+        let start = Instant::now();
+        let mut buffer = WriteBuffer::new(policy(), start);
+        buffer.record_message(100);
+
+        buffer.flushed(start + Duration::from_secs(1));
+        buffer.record_message(1);
+
+        assert!(!buffer.is_due(start + Duration::from_secs(2)));
+        assert!(buffer.is_due(start + Duration::from_secs(3)));
+    }
+}
@@ -0,0 +1,208 @@
+use std::time::Instant;
+
+use flatbuffers::Vector;
+use hdf5::Group;
+
+use crate::ev44::Ev44;
+use crate::writers::storage::DatasetOptions;
+use crate::writers::{
+    write_string_attribute, BufferedDataset, FlushPolicy, WriteBuffer, WriteError, Writer,
+};
+
+/// Writes ev44 messages into an NXevent_data group.
+///
+/// The event data (time of flight and pixel ids) make up the bulk of each message,
+/// so where possible they are handed to HDF5 directly from the flatbuffer without
+/// copying. If the flatbuffer isn't suitably aligned in memory we fall back to
+/// copying via a scratch buffer which is reused between messages.
+///
+/// Smaller messages are buffered and appended in batches according to the flush
+/// policy; a message which on its own exceeds the byte threshold is written
+/// straight through.
+pub struct Ev44Writer {
+    event_time_zero: BufferedDataset<i64>,
+    event_index: BufferedDataset<i64>,
+    event_time_offset: BufferedDataset<i32>,
+    event_id: BufferedDataset<i32>,
+    buffer: WriteBuffer,
+    scratch: Vec<i32>,
+    time_scratch: Vec<i64>,
+}
+
+impl Ev44Writer {
+    /// Event data arrives in large blocks, so the chunks are much bigger than for
+    /// the other writers and compression is off to keep up with the data rate.
+    pub fn default_options() -> DatasetOptions {
+        DatasetOptions::new(1 << 20)
+    }
+
+    pub fn create(
+        group: &Group,
+        options: &DatasetOptions,
+        policy: FlushPolicy,
+    ) -> hdf5::Result<Ev44Writer> {
+        let event_time_zero = options.create_dataset::<i64>(group, "event_time_zero")?;
+        write_string_attribute(&event_time_zero, "units", "ns")?;
+        let event_index = options.create_dataset::<i64>(group, "event_index")?;
+        let event_time_offset = options.create_dataset::<i32>(group, "event_time_offset")?;
+        write_string_attribute(&event_time_offset, "units", "ns")?;
+        let event_id = options.create_dataset::<i32>(group, "event_id")?;
+
+        Ok(Ev44Writer {
+            event_time_zero: BufferedDataset::new(event_time_zero),
+            event_index: BufferedDataset::new(event_index),
+            event_time_offset: BufferedDataset::new(event_time_offset),
+            event_id: BufferedDataset::new(event_id),
+            buffer: WriteBuffer::new(policy, Instant::now()),
+            scratch: Vec::new(),
+            time_scratch: Vec::new(),
+        })
+    }
+
+    pub fn write_message(&mut self, message: &Ev44) -> hdf5::Result<()> {
+        let reference_time = message.reference_time();
+        self.event_time_zero
+            .extend_from_slice(as_native_slice(reference_time, &mut self.time_scratch));
+
+        // The indexes in the message are relative to the message, but in the file
+        // they are relative to all the events written so far.
+        let events_so_far = self.event_time_offset.len() as i64;
+        for i in message.reference_time_index() {
+            self.event_index.push(events_so_far + i as i64);
+        }
+
+        let pulse_bytes = reference_time.len() * 2 * std::mem::size_of::<i64>();
+        let event_bytes = message.num_events() * 2 * std::mem::size_of::<i32>();
+        let write_through = event_bytes >= self.buffer.policy().max_bytes;
+
+        let tofs = as_native_slice(message.time_of_flight(), &mut self.scratch);
+        if write_through {
+            self.event_time_offset.write_through(tofs)?;
+        } else {
+            self.event_time_offset.extend_from_slice(tofs);
+        }
+
+        let pixels = as_native_slice(message.pixel_id(), &mut self.scratch);
+        if write_through {
+            self.event_id.write_through(pixels)?;
+        } else {
+            self.event_id.extend_from_slice(pixels);
+        }
+
+        // Events which were written through are already in the file, so only the
+        // pulse times and indexes count towards the next flush.
+        let buffered = if write_through {
+            pulse_bytes
+        } else {
+            pulse_bytes + event_bytes
+        };
+        self.buffer.record_message(buffered);
+        self.flush_if_due(Instant::now())
+    }
+
+    /// The number of events written, including those still buffered.
+    pub fn events_written(&self) -> usize {
+        self.event_time_offset.len()
+    }
+}
+
+impl Writer for Ev44Writer {
+    fn write(&mut self, buf: &[u8]) -> Result<(), WriteError> {
+        let message = Ev44::from_bytes(buf)?;
+        self.write_message(&message)?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> hdf5::Result<()> {
+        self.event_time_zero.flush()?;
+        self.event_index.flush()?;
+        self.event_time_offset.flush()?;
+        self.event_id.flush()?;
+        self.buffer.flushed(Instant::now());
+        Ok(())
+    }
+
+    fn flush_if_due(&mut self, now: Instant) -> hdf5::Result<()> {
+        if self.buffer.is_due(now) {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn lengths(&self) -> Vec<(String, usize)> {
+        vec![
+            (self.event_time_zero.path(), self.event_time_zero.written()),
+            (self.event_index.path(), self.event_index.written()),
+            (
+                self.event_time_offset.path(),
+                self.event_time_offset.written(),
+            ),
+            (self.event_id.path(), self.event_id.written()),
+        ]
+    }
+}
+
+/// Gets a flatbuffer vector as a native slice.
+///
+/// Flatbuffers are little-endian, so on little-endian machines the underlying bytes
+/// can be used as-is provided they are aligned for `T`. Otherwise, the values are
+/// copied into `scratch`, which keeps its allocation so repeated calls don't allocate.
+pub fn as_native_slice<'a, T>(v: Vector<'a, T>, scratch: &'a mut Vec<T>) -> &'a [T]
+where
+    T: bytemuck::Pod + flatbuffers::Follow<'a, Inner = T> + 'a,
+{
+    if cfg!(target_endian = "little") {
+        if let Ok(values) = bytemuck::try_cast_slice::<u8, T>(v.bytes()) {
+            return values;
+        }
+    }
+    scratch.clear();
+    scratch.extend(v.iter());
+    scratch
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ev44_events_generated::root_as_event_44_message;
+    use crate::fixtures::Ev44Builder;
+
+    use super::*;
+
+    fn create_flatbuffer() -> Vec<u8> {
+        Ev44Builder::new("SomeSource")
+            .pulse(123456, &[100, 200, 300, 4000, 50000], &[1, 2, 3, 4, 5])
+            .build()
+    }
+
+    #[test]
+    fn aligned_vector_is_not_copied() {
+        let buf = create_flatbuffer();
+        let root = root_as_event_44_message(&buf).unwrap();
+        let time_of_flight = root.time_of_flight().unwrap();
+        let mut scratch = Vec::new();
+
+        let tofs = as_native_slice(time_of_flight, &mut scratch);
+
+        assert_eq!(tofs, &[100, 200, 300, 4000, 50000]);
+        assert_eq!(tofs.as_ptr() as *const u8, time_of_flight.bytes().as_ptr());
+    }
+
+    #[test]
+    fn misaligned_vector_is_copied_into_scratch() {
+        let buf = create_flatbuffer();
+        // Shift the whole buffer by one byte so the vectors are no longer aligned in
+        // memory, although they are still valid relative to the start of the buffer.
+        let mut shifted = vec![0_u8; buf.len() + 1];
+        shifted[1..].copy_from_slice(&buf);
+        let root = root_as_event_44_message(&shifted[1..]).unwrap();
+        let time_of_flight = root.time_of_flight().unwrap();
+        let mut scratch = Vec::with_capacity(16);
+        let capacity = scratch.capacity();
+
+        let tofs = as_native_slice(time_of_flight, &mut scratch);
+
+        assert_eq!(tofs, &[100, 200, 300, 4000, 50000]);
+        assert_ne!(tofs.as_ptr() as *const u8, time_of_flight.bytes().as_ptr());
+        assert_eq!(scratch.capacity(), capacity);
+    }
+}
@@ -0,0 +1,201 @@
+use std::time::Instant;
+
+use hdf5::Group;
+
+use crate::f144_logdata_generated::{root_as_log_data, LogData, Value};
+use crate::writers::storage::DatasetOptions;
+use crate::writers::{
+    write_string_attribute, BufferedDataset, FlushPolicy, WriteBuffer, WriteError, Writer,
+};
+
+/// A scalar value pulled out of an f144 message, before conversion to the
+/// dataset's type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScalarValue {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+}
+
+macro_rules! log_values {
+    ($($variant:ident($t:ty) = $($dtype:literal)|+),* $(,)?) => {
+        /// The value dataset, typed according to the configured dtype.
+        enum LogValues {
+            $($variant(BufferedDataset<$t>),)*
+        }
+
+        /// The dtypes an f144 module can be configured with.
+        pub const DTYPES: &[&str] = &[$($($dtype),+),*];
+
+        impl LogValues {
+            fn create(
+                group: &Group,
+                dtype: &str,
+                options: &DatasetOptions,
+            ) -> hdf5::Result<LogValues> {
+                match dtype {
+                    $($($dtype)|+ => Ok(LogValues::$variant(BufferedDataset::new(
+                        options.create_dataset::<$t>(group, "value")?,
+                    ))),)*
+                    _ => Err(format!("unsupported f144 dtype: {dtype}").into()),
+                }
+            }
+
+            fn push(&mut self, value: ScalarValue) {
+                match self {
+                    $(LogValues::$variant(ds) => ds.push(match value {
+                        ScalarValue::Int(v) => v as $t,
+                        ScalarValue::UInt(v) => v as $t,
+                        ScalarValue::Float(v) => v as $t,
+                    }),)*
+                }
+            }
+
+            fn flush(&mut self) -> hdf5::Result<()> {
+                match self {
+                    $(LogValues::$variant(ds) => ds.flush(),)*
+                }
+            }
+
+            fn length(&self) -> (String, usize) {
+                match self {
+                    $(LogValues::$variant(ds) => (ds.path(), ds.written()),)*
+                }
+            }
+        }
+    };
+}
+
+log_values! {
+    Int8(i8) = "int8" | "byte",
+    UInt8(u8) = "uint8" | "ubyte",
+    Int16(i16) = "int16" | "short",
+    UInt16(u16) = "uint16" | "ushort",
+    Int32(i32) = "int32" | "int",
+    UInt32(u32) = "uint32" | "uint",
+    Int64(i64) = "int64" | "long",
+    UInt64(u64) = "uint64" | "ulong",
+    Float32(f32) = "float32" | "float",
+    Float64(f64) = "float64" | "double",
+}
+
+/// Writes f144 messages into an NXlog group.
+///
+/// Only scalar values are supported for now; values are converted to the dtype
+/// given in the module's config.
+pub struct F144Writer {
+    value: LogValues,
+    time: BufferedDataset<i64>,
+    buffer: WriteBuffer,
+}
+
+impl F144Writer {
+    pub fn default_options() -> DatasetOptions {
+        DatasetOptions::new(1024)
+    }
+
+    pub fn create(
+        group: &Group,
+        dtype: &str,
+        value_units: Option<&str>,
+        options: &DatasetOptions,
+        policy: FlushPolicy,
+    ) -> hdf5::Result<F144Writer> {
+        let value = LogValues::create(group, dtype, options)?;
+        if let Some(units) = value_units {
+            write_string_attribute(&group.dataset("value")?, "units", units)?;
+        }
+        let time = options.create_dataset::<i64>(group, "time")?;
+        write_string_attribute(&time, "units", "ns")?;
+
+        Ok(F144Writer {
+            value,
+            time: BufferedDataset::new(time),
+            buffer: WriteBuffer::new(policy, Instant::now()),
+        })
+    }
+
+    pub fn write_message(&mut self, message: &LogData) -> Result<(), WriteError> {
+        let Some(value) = extract_scalar(message) else {
+            return Err(hdf5::Error::from("f144 value type not supported").into());
+        };
+        self.value.push(value);
+        self.time.push(message.timestamp());
+        self.buffer.record_message(2 * std::mem::size_of::<i64>());
+        self.flush_if_due(Instant::now())?;
+        Ok(())
+    }
+}
+
+impl Writer for F144Writer {
+    fn write(&mut self, buf: &[u8]) -> Result<(), WriteError> {
+        let message = root_as_log_data(buf)?;
+        self.write_message(&message)
+    }
+
+    fn flush(&mut self) -> hdf5::Result<()> {
+        self.value.flush()?;
+        self.time.flush()?;
+        self.buffer.flushed(Instant::now());
+        Ok(())
+    }
+
+    fn flush_if_due(&mut self, now: Instant) -> hdf5::Result<()> {
+        if self.buffer.is_due(now) {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn lengths(&self) -> Vec<(String, usize)> {
+        vec![self.value.length(), (self.time.path(), self.time.written())]
+    }
+}
+
+fn extract_scalar(message: &LogData) -> Option<ScalarValue> {
+    let value = match message.value_type() {
+        Value::Byte => ScalarValue::Int(message.value_as_byte()?.value() as i64),
+        Value::UByte => ScalarValue::UInt(message.value_as_ubyte()?.value() as u64),
+        Value::Short => ScalarValue::Int(message.value_as_short()?.value() as i64),
+        Value::UShort => ScalarValue::UInt(message.value_as_ushort()?.value() as u64),
+        Value::Int => ScalarValue::Int(message.value_as_int()?.value() as i64),
+        Value::UInt => ScalarValue::UInt(message.value_as_uint()?.value() as u64),
+        Value::Long => ScalarValue::Int(message.value_as_long()?.value()),
+        Value::ULong => ScalarValue::UInt(message.value_as_ulong()?.value()),
+        Value::Float => ScalarValue::Float(message.value_as_float()?.value() as f64),
+        Value::Double => ScalarValue::Float(message.value_as_double()?.value()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixtures::F144Builder;
+
+    use super::*;
+
+    #[test]
+    fn extract_double_value() {
+        let buf = F144Builder::new("SomeSource", 1.5).build();
+        let message = root_as_log_data(&buf).unwrap();
+
+        assert_eq!(extract_scalar(&message), Some(ScalarValue::Float(1.5)));
+    }
+
+    #[test]
+    fn extract_int_value() {
+        let buf = F144Builder::new("SomeSource", -7).build();
+        let message = root_as_log_data(&buf).unwrap();
+
+        assert_eq!(extract_scalar(&message), Some(ScalarValue::Int(-7)));
+    }
+
+    #[test]
+    fn array_values_are_not_extracted_as_scalars() {
+        let buf = F144Builder::new("SomeSource", vec![1.0, 2.0]).build();
+        let message = root_as_log_data(&buf).unwrap();
+
+        assert_eq!(extract_scalar(&message), None);
+    }
+}
@@ -0,0 +1,249 @@
+use hdf5::filters::Filter;
+use serde::{Deserialize, Serialize};
+
+/// Filter ids registered with The HDF Group for the third-party compression
+/// plugins. These are only usable if the plugin can be found at runtime, e.g. via
+/// HDF5_PLUGIN_PATH.
+const LZF_FILTER_ID: i32 = 32000;
+const BLOSC_FILTER_ID: i32 = 32001;
+const LZ4_FILTER_ID: i32 = 32004;
+
+/// Compression applied to a module's datasets, as given in the module's config.
+///
+/// E.g. `"compression": {"filter": "gzip", "level": 4, "shuffle": true}`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "filter", rename_all = "lowercase")]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip {
+        #[serde(default = "default_gzip_level")]
+        level: u8,
+        #[serde(default)]
+        shuffle: bool,
+    },
+    Lzf {
+        #[serde(default)]
+        shuffle: bool,
+    },
+    Lz4 {
+        #[serde(default)]
+        shuffle: bool,
+    },
+    Blosc {
+        #[serde(default)]
+        compressor: BloscCompressor,
+        #[serde(default = "default_blosc_level")]
+        level: u8,
+        #[serde(default)]
+        shuffle: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BloscCompressor {
+    BloscLz,
+    #[default]
+    Lz4,
+    Lz4hc,
+    Snappy,
+    Zlib,
+    Zstd,
+}
+
+fn default_gzip_level() -> u8 {
+    4
+}
+
+fn default_blosc_level() -> u8 {
+    5
+}
+
+impl Compression {
+    /// The HDF5 filter pipeline for this compression.
+    pub fn filters(&self) -> Result<Vec<Filter>, &'static str> {
+        let (shuffle, filter) = match *self {
+            Compression::None => return Ok(Vec::new()),
+            Compression::Gzip { level, shuffle } => {
+                if level > 9 {
+                    return Err("gzip level must be between 0 and 9");
+                }
+                (shuffle, Filter::deflate(level))
+            }
+            Compression::Lzf { shuffle } => (shuffle, Filter::user(LZF_FILTER_ID, &[])),
+            Compression::Lz4 { shuffle } => (shuffle, Filter::user(LZ4_FILTER_ID, &[])),
+            Compression::Blosc {
+                compressor,
+                level,
+                shuffle,
+            } => {
+                if level > 9 {
+                    return Err("blosc level must be between 0 and 9");
+                }
+                // Blosc does its own shuffling; the first four values are filled
+                // in by the plugin.
+                let cdata = [0, 0, 0, 0, level as u32, shuffle as u32, compressor as u32];
+                return Ok(vec![Filter::user(BLOSC_FILTER_ID, &cdata)]);
+            }
+        };
+        if shuffle {
+            Ok(vec![Filter::shuffle(), filter])
+        } else {
+            Ok(vec![filter])
+        }
+    }
+}
+
+/// The chunking and compression settings which can be given in any streaming
+/// module's config. Anything not given falls back to the writer's defaults.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageSettings {
+    pub chunk_size: Option<usize>,
+    pub compression: Option<Compression>,
+}
+
+/// The chunking and compression used when creating a writer's datasets.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatasetOptions {
+    pub chunk_size: usize,
+    pub compression: Compression,
+}
+
+impl DatasetOptions {
+    pub fn new(chunk_size: usize) -> DatasetOptions {
+        DatasetOptions {
+            chunk_size,
+            compression: Compression::None,
+        }
+    }
+
+    /// Applies the module's settings on top of these defaults.
+    pub fn with_settings(&self, settings: &StorageSettings) -> DatasetOptions {
+        DatasetOptions {
+            chunk_size: settings.chunk_size.unwrap_or(self.chunk_size),
+            compression: settings
+                .compression
+                .clone()
+                .unwrap_or(self.compression.clone()),
+        }
+    }
+
+    /// Creates an extendible one-dimensional dataset with these options.
+    pub fn create_dataset<T: hdf5::H5Type>(
+        &self,
+        group: &hdf5::Group,
+        name: &str,
+    ) -> hdf5::Result<hdf5::Dataset> {
+        let filters = self.compression.filters()?;
+        group
+            .new_dataset::<T>()
+            .chunk(self.chunk_size)
+            .set_filters(&filters)
+            .shape(0..)
+            .create(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> StorageSettings {
+        serde_json::from_str(data).unwrap()
+    }
+
+    #[test]
+    fn settings_are_optional() {
+        let settings = parse("{}");
+
+        assert_eq!(settings, StorageSettings::default());
+    }
+
+    #[test]
+    fn parse_gzip_with_defaults() {
+        let settings = parse(r#"{"compression": {"filter": "gzip"}}"#);
+
+        assert_eq!(
+            settings.compression,
+            Some(Compression::Gzip {
+                level: 4,
+                shuffle: false
+            })
+        );
+    }
+
+    #[test]
+    fn parse_blosc() {
+        let settings = parse(
+            r#"{"chunk_size": 4096,
+                "compression": {"filter": "blosc", "compressor": "zstd", "level": 3, "shuffle": true}}"#,
+        );
+
+        assert_eq!(settings.chunk_size, Some(4096));
+        assert_eq!(
+            settings.compression,
+            Some(Compression::Blosc {
+                compressor: BloscCompressor::Zstd,
+                level: 3,
+                shuffle: true
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_filter_is_an_error() {
+        let result =
+            serde_json::from_str::<StorageSettings>(r#"{"compression": {"filter": "zip"}}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn shuffle_comes_before_compression() {
+        let compression = Compression::Gzip {
+            level: 6,
+            shuffle: true,
+        };
+
+        assert_eq!(
+            compression.filters().unwrap(),
+            vec![Filter::shuffle(), Filter::deflate(6)]
+        );
+    }
+
+    #[test]
+    fn blosc_does_its_own_shuffle() {
+        let compression = Compression::Blosc {
+            compressor: BloscCompressor::Lz4,
+            level: 5,
+            shuffle: true,
+        };
+
+        assert_eq!(
+            compression.filters().unwrap(),
+            vec![Filter::user(BLOSC_FILTER_ID, &[0, 0, 0, 0, 5, 1, 1])]
+        );
+    }
+
+    #[test]
+    fn gzip_level_out_of_range_is_an_error() {
+        let compression = Compression::Gzip {
+            level: 10,
+            shuffle: false,
+        };
+
+        assert!(compression.filters().is_err());
+    }
+
+    #[test]
+    fn module_settings_override_defaults() {
+        let defaults = DatasetOptions::new(1024);
+        let settings = parse(r#"{"compression": {"filter": "lzf"}}"#);
+
+        let options = defaults.with_settings(&settings);
+
+        assert_eq!(options.chunk_size, 1024);
+        assert_eq!(options.compression, Compression::Lzf { shuffle: false });
+    }
+}
@@ -0,0 +1,17 @@
+//! Raising signals affects the whole process, so this has a test binary of its own.
+
+#![cfg(unix)]
+
+use filewriter::signals::{install, stop_requested};
+
+#[test]
+fn first_signal_requests_stop_and_restores_the_default() {
+    install().unwrap();
+
+    unsafe { libc::raise(libc::SIGINT) };
+
+    let mut action: libc::sigaction = unsafe { std::mem::zeroed() };
+    unsafe { libc::sigaction(libc::SIGINT, std::ptr::null(), &mut action) };
+    assert!(stop_requested());
+    assert_eq!(action.sa_sigaction, libc::SIG_DFL);
+}